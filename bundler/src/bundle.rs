@@ -0,0 +1,325 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fs,
+    io,
+    path::Path,
+};
+
+use crate::{
+    lexer::Kind,
+    shake::shake,
+    syntax::{Item, ItemKind, Source},
+    writer::Writer,
+};
+
+pub struct Module {
+    pub name: String,
+    pub vis: String,
+    pub src: Source,
+    pub items: Vec<Item>,
+}
+
+impl Module {
+    pub fn new(name: &str, vis: String, text: String) -> Self {
+        let src = Source::new(text);
+        let items = src.items(0, src.len());
+        Self {
+            name: name.to_string(),
+            vis,
+            src,
+            items,
+        }
+    }
+}
+
+///
+/// A library crate split into modules by the `mod` declarations of lib.rs, together
+/// with the binary that links against it
+///
+pub struct Crate {
+    pub name: String,
+    ///
+    /// features enabled when the bundle is compiled, items disabled by cfg are dropped
+    ///
+    pub features: Vec<String>,
+    pub lib: Module,
+    pub modules: Vec<Module>,
+    pub main: Module,
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+///
+/// package name in Cargo.toml, falls back to directory name
+///
+fn package_name(dir: &Path) -> String {
+    if let Ok(manifest) = fs::read_to_string(dir.join("Cargo.toml")) {
+        for line in manifest.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("name") {
+                let rest = rest.trim_start();
+                if let Some(value) = rest.strip_prefix('=') {
+                    return value.trim().trim_matches('"').replace('-', "_");
+                }
+            }
+        }
+    }
+    dir.file_name()
+        .map(|s| s.to_string_lossy().replace('-', "_"))
+        .unwrap_or_default()
+}
+
+pub fn is_test_module(name: &str) -> bool {
+    name == "tests" || name.ends_with("_test")
+}
+
+impl Crate {
+    pub fn load(dir: &Path, main: &Path, features: &[String]) -> io::Result<Self> {
+        let src_dir = dir.join("src");
+        let lib = Module::new("", String::new(), read(&src_dir.join("lib.rs"))?);
+        let mut modules = Vec::new();
+        for item in &lib.items {
+            if item.kind != ItemKind::Mod || item.body.is_some() || !item.is_enabled(features) {
+                continue;
+            }
+            let name = item.name.clone().unwrap();
+            if is_test_module(&name) {
+                continue;
+            }
+            let text = read(&src_dir.join(format!("{}.rs", name)))?;
+            modules.push(Module::new(&name, lib.src.visibility(item), text));
+        }
+        let main = Module::new("", String::new(), read(main)?);
+        Ok(Self {
+            name: package_name(dir),
+            features: features.to_vec(),
+            lib,
+            modules,
+            main,
+        })
+    }
+
+    pub fn module_index(&self, name: &str) -> Option<usize> {
+        self.modules.iter().position(|m| m.name == name)
+    }
+
+    pub fn is_enabled(&self, item: &Item) -> bool {
+        item.is_enabled(&self.features)
+    }
+
+    fn is_crate_root(&self, s: &str) -> bool {
+        s == "crate" || s == self.name
+    }
+
+    ///
+    /// modules referred through `crate::x` paths by enabled items
+    ///
+    pub fn dependencies(&self, module: &Module) -> BTreeSet<usize> {
+        let src = &module.src;
+        let mut res = BTreeSet::new();
+        for item in &module.items {
+            if !self.is_enabled(item) || item.kind == ItemKind::InnerAttr {
+                continue;
+            }
+            if item.kind == ItemKind::Use {
+                for path in src.use_paths(item) {
+                    if path.segments.len() >= 2 && self.is_crate_root(&path.segments[0]) {
+                        res.extend(self.module_index(&path.segments[1]));
+                    }
+                }
+            }
+            for i in item.start..item.end {
+                if let Some(root) = src.ident(i) {
+                    if self.is_crate_root(root) && src.is_path_sep(i + 1) {
+                        if let Some(name) = src.ident(i + 3) {
+                            res.extend(self.module_index(name));
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    ///
+    /// modules reachable from main.rs and the entry modules
+    ///
+    pub fn reachable(&self, entries: &[String]) -> Vec<bool> {
+        let mut visited = vec![false; self.modules.len()];
+        let mut queue: VecDeque<usize> = self.dependencies(&self.main).into_iter().collect();
+        queue.extend(entries.iter().filter_map(|e| self.module_index(e)));
+        while let Some(m) = queue.pop_front() {
+            if visited[m] {
+                continue;
+            }
+            visited[m] = true;
+            queue.extend(self.dependencies(&self.modules[m]));
+        }
+        visited
+    }
+}
+
+///
+/// Replace code tokens [from, to) with text, or remove them if text is None
+///
+pub struct Edit {
+    pub from: usize,
+    pub to: usize,
+    pub text: Option<String>,
+}
+
+///
+/// Emit code tokens [from, to) of src, comments are dropped and paths rooted at
+/// `rename` are rewritten to `crate::`
+///
+pub fn render(src: &Source, from: usize, to: usize, edits: &[Edit], rename: &str, w: &mut Writer) {
+    let (tf, tt) = src.token_range(from, to);
+    if tf >= tt {
+        return;
+    }
+    let mut edits: Vec<(usize, usize, &Option<String>)> = edits
+        .iter()
+        .map(|e| {
+            let (l, r) = src.token_range(e.from, e.to);
+            (l, r, &e.text)
+        })
+        .collect();
+    edits.sort_by_key(|e| e.0);
+    let mut edits = edits.into_iter().peekable();
+    let tokens = &src.tokens;
+    let text = &src.text;
+    let mut prev = tokens[tf].start;
+    let mut t = tf;
+    while t < tt {
+        if let Some(&(l, r, replace)) = edits.peek() {
+            if l == t {
+                edits.next();
+                w.code(&text[prev..tokens[t].start]);
+                if let Some(replace) = replace {
+                    w.code(replace);
+                }
+                prev = tokens[r - 1].end;
+                t = r;
+                continue;
+            }
+        }
+        let token = &tokens[t];
+        w.code(&text[prev..token.start]);
+        match token.kind {
+            Kind::Comment => {}
+            Kind::Literal => w.raw(token.text(text)),
+            Kind::Ident
+                if token.text(text) == rename
+                    && t + 2 < tokens.len()
+                    && tokens[t + 1].start == token.end
+                    && tokens[t + 1].text(text) == ":"
+                    && tokens[t + 2].text(text) == ":" =>
+            {
+                w.code("crate")
+            }
+            _ => w.code(token.text(text)),
+        }
+        prev = token.end;
+        t += 1;
+    }
+}
+
+///
+/// Standard edits of a module: drop tests, disabled items and inner attributes, flatten use trees
+///
+pub fn module_edits(krate: &Crate, module: &Module) -> Vec<Edit> {
+    let src = &module.src;
+    let mut edits = Vec::new();
+    for item in &module.items {
+        if !krate.is_enabled(item) || item.kind == ItemKind::InnerAttr {
+            edits.push(Edit {
+                from: item.start,
+                to: item.end,
+                text: None,
+            });
+        } else if item.kind == ItemKind::Use {
+            let vis = src.visibility(item);
+            let mut lines: Vec<String> = Vec::new();
+            for mut path in src.use_paths(item) {
+                if path.segments[0] == krate.name {
+                    path.segments[0] = "crate".to_string();
+                }
+                let line = path.render(&vis);
+                if !lines.contains(&line) {
+                    lines.push(line);
+                }
+            }
+            let mut text = String::new();
+            for attr in &item.attrs {
+                text.push_str(attr);
+                text.push('\n');
+            }
+            text.push_str(&lines.join("\n"));
+            edits.push(Edit {
+                from: item.start,
+                to: item.end,
+                text: Some(text),
+            });
+        }
+    }
+    edits
+}
+
+pub fn render_module(krate: &Crate, module: &Module, edits: &[Edit], w: &mut Writer) {
+    w.line(&format!("{}mod {} {{", module.vis, module.name));
+    w.indent();
+    render(&module.src, 0, module.src.len(), edits, &krate.name, w);
+    w.dedent();
+    w.line("}");
+}
+
+fn inner_attributes(module: &Module) -> Vec<String> {
+    module
+        .items
+        .iter()
+        .filter(|item| item.kind == ItemKind::InnerAttr)
+        .map(|item| module.src.slice(item.start, item.end).to_string())
+        .collect()
+}
+
+///
+/// Inline reachable modules into a single file, main.rs goes last
+///
+/// Unused items are removed as well if `shake_tree` is set
+///
+pub fn bundle(krate: &Crate, entries: &[String], header: &[String], shake_tree: bool) -> String {
+    let reachable = krate.reachable(entries);
+    let (edits, main_edits) = if shake_tree {
+        let entries: Vec<usize> = entries.iter().filter_map(|e| krate.module_index(e)).collect();
+        shake(krate, &reachable, &entries)
+    } else {
+        let edits = krate
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| if reachable[i] { Some(module_edits(krate, m)) } else { None })
+            .collect();
+        (edits, module_edits(krate, &krate.main))
+    };
+    let mut w = Writer::new();
+    for line in header {
+        w.line(line);
+    }
+    let mut attrs = inner_attributes(&krate.lib);
+    attrs.extend(inner_attributes(&krate.main));
+    attrs.dedup();
+    for attr in attrs {
+        w.line(&attr);
+    }
+    for (module, edits) in krate.modules.iter().zip(edits.iter()) {
+        if let Some(edits) = edits {
+            render_module(krate, module, edits, &mut w);
+        }
+    }
+    w.newline();
+    render(&krate.main.src, 0, krate.main.src.len(), &main_edits, &krate.name, &mut w);
+    w.finish()
+}
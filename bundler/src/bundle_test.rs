@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process::Command};
+
+    use crate::bundle::{bundle, Crate};
+
+    fn compile(code: &str) -> bool {
+        let out = env::temp_dir().join(format!("bundler_test_{}_{}", std::process::id(), code.len()));
+        fs::create_dir_all(&out).unwrap();
+        let file = out.join("inline.rs");
+        fs::write(&file, code).unwrap();
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let status = Command::new(rustc)
+            .args(["--edition", "2021", "--crate-type", "bin", "--cap-lints", "allow", "--out-dir"])
+            .arg(&out)
+            .arg(&file)
+            .status()
+            .unwrap();
+        fs::remove_dir_all(&out).ok();
+        status.success()
+    }
+
+    fn load() -> Crate {
+        load_with(&[])
+    }
+
+    fn load_with(features: &[String]) -> Crate {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../contest");
+        Crate::load(&dir, &dir.join("src/main.rs"), features).unwrap()
+    }
+
+    #[test]
+    fn test_contest_compiles_alone() {
+        let krate = load();
+        let code = bundle(&krate, &["solver".to_string()], &[], false);
+        assert!(code.contains("pub mod solver {"));
+        assert!(!code.contains("#[test]"));
+        assert!(!code.contains("_test {"));
+        assert!(!code.contains("contest::"));
+        assert!(compile(&code));
+    }
+
+    #[test]
+    fn test_shake() {
+        let krate = load();
+        let full = bundle(&krate, &["solver".to_string()], &[], false);
+        let code = bundle(&krate, &["solver".to_string()], &[], true);
+        assert!(code.len() < full.len());
+        assert!(code.contains("pub mod solver {"));
+        assert!(full.contains("MF469762049"));
+        assert!(!code.contains("MF469762049"));
+        assert!(compile(&code));
+    }
+
+    #[test]
+    fn test_features() {
+        let code = bundle(&load(), &["solver".to_string()], &[], true);
+        assert!(code.contains("#[cfg(not(any(feature = \"stress\", feature = \"samples\")))]"));
+        assert!(!code.contains("#[cfg(all(feature = \"samples\", not(feature = \"stress\")))]"));
+        assert!(!code.contains("fn run_samples"));
+        let code = bundle(&load_with(&["samples".to_string()]), &["solver".to_string()], &[], true);
+        assert!(!code.contains("#[cfg(not(any(feature = \"stress\", feature = \"samples\")))]"));
+        assert!(code.contains("#[cfg(all(feature = \"samples\", not(feature = \"stress\")))]"));
+        assert!(code.contains("fn run_samples"));
+    }
+}
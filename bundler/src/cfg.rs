@@ -0,0 +1,74 @@
+///
+/// Evaluate the predicate of a `#[cfg(...)]` attribute as the judge compiles the
+/// submission, that is not a test build and only the given features enabled
+///
+/// None if the predicate depends on something unknown, like target or debug_assertions
+///
+pub fn eval(attr: &str, features: &[String]) -> Option<bool> {
+    let s: String = attr.chars().filter(|c| !c.is_whitespace()).collect();
+    let predicate = s.strip_prefix("#[cfg(")?.strip_suffix(")]")?;
+    let (res, rest) = predicate_of(predicate, features)?;
+    if rest.is_empty() {
+        res
+    } else {
+        None
+    }
+}
+
+///
+/// Parse one predicate at the beginning of s, return its value and the rest of s
+///
+fn predicate_of<'a>(s: &'a str, features: &[String]) -> Option<(Option<bool>, &'a str)> {
+    let name_end = s
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    let (name, rest) = s.split_at(name_end);
+    if let Some(rest) = rest.strip_prefix('=') {
+        let rest = rest.strip_prefix('"')?;
+        let end = rest.find('"')?;
+        let value = &rest[..end];
+        let res = if name == "feature" {
+            Some(features.iter().any(|f| f == value))
+        } else {
+            None
+        };
+        return Some((res, &rest[end + 1..]));
+    }
+    if let Some(mut rest) = rest.strip_prefix('(') {
+        let mut args = Vec::new();
+        while !rest.starts_with(')') {
+            let (arg, r) = predicate_of(rest, features)?;
+            args.push(arg);
+            rest = r.strip_prefix(',').unwrap_or(r);
+            if !r.starts_with(',') && !r.starts_with(')') {
+                return None;
+            }
+        }
+        let rest = &rest[1..];
+        let res = match name {
+            "not" if args.len() == 1 => args[0].map(|x| !x),
+            "any" => {
+                if args.contains(&Some(true)) {
+                    Some(true)
+                } else if args.iter().all(|x| *x == Some(false)) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            "all" => {
+                if args.contains(&Some(false)) {
+                    Some(false)
+                } else if args.iter().all(|x| *x == Some(true)) {
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        return Some((res, rest));
+    }
+    let res = if name == "test" { Some(false) } else { None };
+    Some((res, rest))
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::{cfg::eval, syntax::Source};
+
+    #[test]
+    fn test_eval() {
+        let features = vec!["stress".to_string()];
+        assert_eq!(Some(false), eval("#[cfg(test)]", &features));
+        assert_eq!(Some(true), eval("#[cfg(feature = \"stress\")]", &features));
+        assert_eq!(Some(false), eval("#[cfg(feature = \"samples\")]", &features));
+        assert_eq!(
+            Some(false),
+            eval("#[cfg(not(any(feature = \"stress\", feature = \"samples\")))]", &features)
+        );
+        assert_eq!(Some(true), eval("#[cfg(all(not(test), feature = \"stress\"))]", &features));
+        assert_eq!(None, eval("#[cfg(debug_assertions)]", &features));
+        assert_eq!(Some(true), eval("#[cfg(any(debug_assertions, feature = \"stress\"))]", &features));
+        assert_eq!(None, eval("#[inline]", &features));
+        assert_eq!(Some(true), eval("#[cfg(all())]", &features));
+        assert_eq!(Some(false), eval("#[cfg(any())]", &features));
+        assert_eq!(Some(false), eval("#[cfg(all(unix, feature = \"samples\"))]", &features));
+        assert_eq!(None, eval("#[cfg(unix)]", &features));
+        assert_eq!(None, eval("#[cfg_attr(test, derive(Debug))]", &features));
+        assert_eq!(None, eval("#[cfg(feature = \"stress\" test)]", &features));
+        assert_eq!(None, eval("#[cfg(not(test, test))]", &features));
+    }
+
+    #[test]
+    fn test_enabled_items() {
+        let s = "#[cfg(feature = \"samples\")]\nfn a() {}\n#[cfg(not(feature = \"samples\"))]\nfn b() {}\n#[cfg(debug_assertions)]\nfn c() {}\n#[inline]\n#[cfg(test)]\nfn d() {}";
+        let src = Source::new(s.to_string());
+        let items = src.items(0, src.len());
+        let enabled = |features: &[String]| -> Vec<_> {
+            items
+                .iter()
+                .filter(|x| x.is_enabled(features))
+                .map(|x| x.name.clone().unwrap())
+                .collect()
+        };
+        assert_eq!(vec!["b", "c"], enabled(&[]));
+        assert_eq!(vec!["a", "c"], enabled(&["samples".to_string()]));
+    }
+}
@@ -0,0 +1,218 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Ident,
+    Lifetime,
+    Literal,
+    Punct,
+    Open,
+    Close,
+    Comment,
+}
+
+///
+/// A token of rust source, `start..end` is the byte range in the source
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Token {
+    pub kind: Kind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token {
+    pub fn text<'a>(&self, src: &'a str) -> &'a str {
+        &src[self.start..self.end]
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek(&self, k: usize) -> Option<char> {
+        self.src[self.offset..].chars().nth(k)
+    }
+
+    fn bump(&mut self) -> char {
+        let c = self.peek(0).unwrap();
+        self.offset += c.len_utf8();
+        c
+    }
+
+    fn eat_while(&mut self, f: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek(0) {
+            if !f(c) {
+                break;
+            }
+            self.bump();
+        }
+    }
+
+    fn line_comment(&mut self) {
+        self.eat_while(|c| c != '\n');
+    }
+
+    fn block_comment(&mut self) {
+        self.offset += 2;
+        let mut depth = 1;
+        while depth > 0 && self.offset < self.bytes.len() {
+            if self.src[self.offset..].starts_with("/*") {
+                depth += 1;
+                self.offset += 2;
+            } else if self.src[self.offset..].starts_with("*/") {
+                depth -= 1;
+                self.offset += 2;
+            } else {
+                self.bump();
+            }
+        }
+    }
+
+    ///
+    /// consume a quoted literal, the opening quote has not been consumed
+    ///
+    fn quoted(&mut self, quote: char) {
+        self.bump();
+        while let Some(c) = self.peek(0) {
+            self.bump();
+            if c == '\\' {
+                if self.peek(0).is_some() {
+                    self.bump();
+                }
+            } else if c == quote {
+                return;
+            }
+        }
+    }
+
+    ///
+    /// consume `r#*"...."#*`, the `r` has already been consumed
+    ///
+    fn raw_string(&mut self) {
+        let mut hashes = 0;
+        while self.peek(0) == Some('#') {
+            hashes += 1;
+            self.bump();
+        }
+        self.bump();
+        let close = format!("\"{}", "#".repeat(hashes));
+        match self.src[self.offset..].find(&close) {
+            Some(pos) => self.offset += pos + close.len(),
+            None => self.offset = self.bytes.len(),
+        }
+    }
+
+    fn number(&mut self) {
+        let hex = self.src[self.offset..].starts_with("0x");
+        loop {
+            match self.peek(0) {
+                Some(c) if is_ident_continue(c) => {
+                    self.bump();
+                    if !hex && (c == 'e' || c == 'E') && matches!(self.peek(0), Some('+') | Some('-')) {
+                        self.bump();
+                    }
+                }
+                Some('.') if matches!(self.peek(1), Some(d) if d.is_ascii_digit()) => {
+                    self.bump();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        self.eat_while(char::is_whitespace);
+        let start = self.offset;
+        let c = self.peek(0)?;
+        let next = self.peek(1);
+        let kind = if c == '/' && next == Some('/') {
+            self.line_comment();
+            Kind::Comment
+        } else if c == '/' && next == Some('*') {
+            self.block_comment();
+            Kind::Comment
+        } else if c == '"' {
+            self.quoted('"');
+            Kind::Literal
+        } else if (c == 'r' && matches!(next, Some('"') | Some('#')) && self.raw_string_ahead(1))
+            || (c == 'b' && next == Some('r') && self.raw_string_ahead(2))
+        {
+            self.eat_while(|c| c == 'b' || c == 'r');
+            self.raw_string();
+            Kind::Literal
+        } else if c == 'b' && next == Some('"') {
+            self.bump();
+            self.quoted('"');
+            Kind::Literal
+        } else if c == 'b' && next == Some('\'') {
+            self.bump();
+            self.quoted('\'');
+            Kind::Literal
+        } else if c == '\'' {
+            if next == Some('\\') || self.peek(2) == Some('\'') {
+                self.quoted('\'');
+                Kind::Literal
+            } else {
+                self.bump();
+                self.eat_while(is_ident_continue);
+                Kind::Lifetime
+            }
+        } else if c.is_ascii_digit() {
+            self.number();
+            Kind::Literal
+        } else if is_ident_start(c) {
+            if c == 'r' && next == Some('#') {
+                self.offset += 2;
+            }
+            self.eat_while(is_ident_continue);
+            Kind::Ident
+        } else {
+            self.bump();
+            match c {
+                '(' | '[' | '{' => Kind::Open,
+                ')' | ']' | '}' => Kind::Close,
+                _ => Kind::Punct,
+            }
+        };
+        Some(Token {
+            kind,
+            start,
+            end: self.offset,
+        })
+    }
+
+    ///
+    /// whether `#*"` follows the prefix of length `skip`
+    ///
+    fn raw_string_ahead(&self, skip: usize) -> bool {
+        let rest = self.src[self.offset + skip..].trim_start_matches('#');
+        rest.starts_with('"')
+    }
+}
+
+///
+/// Split rust source into tokens, comments are kept as tokens
+///
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let mut lexer = Lexer {
+        src,
+        bytes: src.as_bytes(),
+        offset: 0,
+    };
+    let mut res = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        res.push(token);
+    }
+    res
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::lexer::{tokenize, Kind};
+    use crate::syntax::{ItemKind, Source};
+
+    fn kinds(s: &str) -> Vec<(Kind, String)> {
+        tokenize(s)
+            .iter()
+            .map(|t| (t.kind, t.text(s).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_literal_and_lifetime() {
+        let s = "fn f<'a>(x: &'a str) -> char { let _ = r#\"}\"#; 'x' } // }\n/* /* } */ */";
+        let tokens = kinds(s);
+        assert!(tokens.contains(&(Kind::Lifetime, "'a".to_string())));
+        assert!(tokens.contains(&(Kind::Literal, "r#\"}\"#".to_string())));
+        assert!(tokens.contains(&(Kind::Literal, "'x'".to_string())));
+        assert_eq!(tokens.iter().filter(|t| t.0 == Kind::Comment).count(), 2);
+        assert_eq!(tokens.iter().filter(|t| t.1 == "}").count(), 1);
+    }
+
+    #[test]
+    fn test_number() {
+        let tokens = kinds("0..1e-5 1.5f64 0xff_u8");
+        let texts: Vec<_> = tokens.iter().map(|t| t.1.as_str()).collect();
+        assert_eq!(texts, vec!["0", ".", ".", "1e-5", "1.5f64", "0xff_u8"]);
+    }
+
+    #[test]
+    fn test_items() {
+        let s = "use std::{io::{self, Write}, fmt::Debug as D};\n#[test]\nfn a() {}\nimpl<T> X for [T; 2] { fn b() {} }\nconst C: u8 = { 1 };\nM!(N, u8);\npub(crate) use m;";
+        let src = Source::new(s.to_string());
+        let items = src.items(0, src.len());
+        let kinds: Vec<_> = items.iter().map(|x| x.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![ItemKind::Use, ItemKind::Fn, ItemKind::Impl, ItemKind::Const, ItemKind::MacroCall, ItemKind::Use]
+        );
+        assert!(items[1].is_test());
+        let uses: Vec<_> = src.use_paths(&items[0]).iter().map(|p| p.render("")).collect();
+        assert_eq!(uses, vec!["use std::io;", "use std::io::Write;", "use std::fmt::Debug as D;"]);
+        assert_eq!(src.visibility(&items[5]), "pub(crate) ");
+    }
+}
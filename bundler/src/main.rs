@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bundle::{bundle, Crate};
+
+pub mod bundle;
+pub mod bundle_test;
+pub mod cfg;
+pub mod cfg_test;
+pub mod lexer;
+pub mod lexer_test;
+pub mod shake;
+pub mod syntax;
+pub mod writer;
+
+const USAGE: &str = "\
+usage: bundler [options]
+
+Inline the modules reachable from the entry module and main.rs of a crate into one file
+
+options:
+    --crate <dir>      crate to bundle, default contest
+    --entry <module>   entry module, default solver
+    --main <file>      binary source, default <crate>/src/main.rs
+    --output <file>    output file, default <crate>/inline.rs, `-` for stdout
+    --features <list>  comma separated features enabled in the bundle, default none
+    --keep-unused      keep unused items of the inlined modules
+";
+
+struct Options {
+    krate: PathBuf,
+    entry: String,
+    main: Option<PathBuf>,
+    output: Option<PathBuf>,
+    features: Vec<String>,
+    keep_unused: bool,
+}
+
+fn parse_args() -> Options {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut res = Options {
+        krate: workspace.join("contest"),
+        entry: "solver".to_string(),
+        main: None,
+        output: None,
+        features: Vec::new(),
+        keep_unused: false,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || match args.next() {
+            Some(v) => v,
+            None => {
+                eprint!("missing value of {}\n\n{}", arg, USAGE);
+                process::exit(2);
+            }
+        };
+        match arg.as_str() {
+            "--crate" => res.krate = PathBuf::from(value()),
+            "--entry" => res.entry = value(),
+            "--main" => res.main = Some(PathBuf::from(value())),
+            "--output" | "-o" => res.output = Some(PathBuf::from(value())),
+            "--features" => {
+                res.features = value()
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            }
+            "--keep-unused" => res.keep_unused = true,
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => {
+                eprint!("unknown option {}\n\n{}", arg, USAGE);
+                process::exit(2);
+            }
+        }
+    }
+    res
+}
+
+///
+/// current UTC time as `yyyy-mm-dd hh:mm:ss`
+///
+fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let (days, rem) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+    //civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        y,
+        m,
+        d,
+        rem / 3600,
+        rem / 60 % 60,
+        rem % 60
+    )
+}
+
+fn main() {
+    let options = parse_args();
+    let main = options
+        .main
+        .clone()
+        .unwrap_or_else(|| options.krate.join("src").join("main.rs"));
+    let krate = match Crate::load(&options.krate, &main, &options.features) {
+        Ok(krate) => krate,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    if krate.module_index(&options.entry).is_none() {
+        eprintln!("module {} is not declared in lib.rs", options.entry);
+        process::exit(1);
+    }
+    let header = vec![
+        "//Generated by bundler".to_string(),
+        format!("//Timestamp: {}", timestamp()),
+    ];
+    let code = bundle(
+        &krate,
+        std::slice::from_ref(&options.entry),
+        &header,
+        !options.keep_unused,
+    );
+    let output = options
+        .output
+        .clone()
+        .unwrap_or_else(|| options.krate.join("inline.rs"));
+    if output.as_os_str() == "-" {
+        print!("{}", code);
+    } else if let Err(e) = fs::write(&output, code) {
+        eprintln!("{}: {}", output.display(), e);
+        process::exit(1);
+    } else {
+        eprintln!("write {}", output.display());
+    }
+}
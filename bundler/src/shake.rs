@@ -0,0 +1,443 @@
+use std::collections::HashSet;
+
+use crate::{
+    bundle::{Crate, Edit, Module},
+    lexer::Kind,
+    syntax::{Item, ItemKind, Source},
+};
+
+const PRIMITIVES: [&str; 17] = [
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize",
+    "f32", "f64", "bool", "char", "str",
+];
+
+///
+/// traits from std whose methods might be called without naming the trait
+///
+const STD_TRAITS: [&str; 12] = [
+    "Write", "Read", "BufRead", "Hash", "Hasher", "FromStr", "Iterator", "FromIterator",
+    "Extend", "DoubleEndedIterator", "ExactSizeIterator", "IntoIterator",
+];
+
+enum Cond {
+    Root,
+    Named(String),
+    ///
+    /// trait is alive if it is named or one of its methods is named
+    ///
+    Trait(String, Vec<String>),
+    ///
+    /// impl is alive if both the trait and the self type are alive or not defined locally
+    ///
+    Impl(Option<String>, Option<String>),
+    ///
+    /// item level macro call, alive if the name it defines and the local names it
+    /// mentions are alive
+    ///
+    Macro(Option<String>, Vec<String>),
+    ///
+    /// associated function of an inherent impl
+    ///
+    Method(String),
+    ///
+    /// other associated items of an alive impl
+    ///
+    Always,
+}
+
+struct Node {
+    module: usize,
+    start: usize,
+    end: usize,
+    parent: Option<usize>,
+    cond: Cond,
+    refs: Vec<String>,
+    alive: bool,
+}
+
+fn idents(src: &Source, l: usize, r: usize) -> Vec<String> {
+    let mut set = HashSet::new();
+    for i in l..r {
+        if let Some(s) = src.ident(i) {
+            set.insert(s.to_string());
+        }
+    }
+    set.into_iter().collect()
+}
+
+///
+/// first index on angle bracket depth 0 within [l, r) where f holds
+///
+fn find_top_level(src: &Source, l: usize, r: usize, f: impl Fn(usize) -> bool) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut i = l;
+    while i < r {
+        match src.kind(i) {
+            Some(Kind::Open) => {
+                if depth == 0 && f(i) {
+                    return Some(i);
+                }
+                i = src.matching(i) + 1;
+                continue;
+            }
+            Some(Kind::Punct) if src.is(i, "<") => depth += 1,
+            Some(Kind::Punct) if src.is(i, ">") && !src.is(i.wrapping_sub(1), "-") => depth -= 1,
+            _ => {
+                if depth == 0 && f(i) {
+                    return Some(i);
+                }
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+///
+/// the name of a path type, `std::ops::Add<T>` -> `Add`, `&mut [T]` -> None
+///
+fn path_name(src: &Source, l: usize, r: usize) -> Option<String> {
+    let mut i = l;
+    while i < r && (src.is(i, "&") || src.is(i, "mut") || src.is(i, "dyn") || src.kind(i) == Some(Kind::Lifetime)) {
+        i += 1;
+    }
+    let mut name = None;
+    while i < r {
+        match src.ident(i) {
+            Some(s) => name = Some(s.to_string()),
+            None => return None,
+        }
+        if src.is_path_sep(i + 1) {
+            i += 3;
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+///
+/// generic parameter names declared by `impl<...>`
+///
+fn generic_params(src: &Source, keyword: usize) -> (Vec<String>, usize) {
+    let mut params = Vec::new();
+    if !src.is(keyword, "<") {
+        return (params, keyword);
+    }
+    let mut depth = 0;
+    let mut expect = true;
+    let mut i = keyword;
+    while i < src.len() {
+        if src.kind(i) == Some(Kind::Open) {
+            i = src.matching(i) + 1;
+            continue;
+        }
+        if src.is(i, "<") {
+            depth += 1;
+            if depth == 1 {
+                expect = true;
+                i += 1;
+                continue;
+            }
+        } else if src.is(i, ">") && !src.is(i - 1, "-") {
+            depth -= 1;
+            if depth == 0 {
+                return (params, i + 1);
+            }
+        } else if depth == 1 && src.is(i, ",") {
+            expect = true;
+        } else if depth == 1 && expect {
+            if let Some(name) = src.ident(i) {
+                if name != "const" {
+                    params.push(name.to_string());
+                    expect = false;
+                }
+            }
+        }
+        i += 1;
+    }
+    (params, i)
+}
+
+struct Shaker<'a> {
+    krate: &'a Crate,
+    nodes: Vec<Node>,
+    defined: HashSet<String>,
+    live: HashSet<String>,
+}
+
+impl<'a> Shaker<'a> {
+    fn module(&self, m: usize) -> &'a Module {
+        if m < self.krate.modules.len() {
+            &self.krate.modules[m]
+        } else {
+            &self.krate.main
+        }
+    }
+
+    fn push(&mut self, module: usize, start: usize, end: usize, parent: Option<usize>, cond: Cond, refs: Vec<String>) -> usize {
+        self.nodes.push(Node {
+            module,
+            start,
+            end,
+            parent,
+            cond,
+            refs,
+            alive: false,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn is_local(&self, name: &Option<String>) -> bool {
+        match name {
+            Some(name) => self.defined.contains(name),
+            None => false,
+        }
+    }
+
+    fn add_impl(&mut self, m: usize, item: &Item) {
+        let src = &self.module(m).src;
+        let (body_l, body_r) = match item.body {
+            Some(body) => body,
+            None => return,
+        };
+        let (params, after) = generic_params(src, item.keyword);
+        let header_end = find_top_level(src, after, body_l, |i| src.is(i, "where")).unwrap_or(body_l - 1);
+        let (trait_name, self_name) = match find_top_level(src, after, header_end, |i| src.is(i, "for")) {
+            Some(f) => (path_name(src, after, f), path_name(src, f + 1, header_end)),
+            None => (None, path_name(src, after, header_end)),
+        };
+        let self_name = self_name.filter(|s| !params.contains(s));
+        let is_trait = trait_name.is_some();
+        let refs = if is_trait {
+            idents(src, item.start, item.end)
+        } else {
+            idents(src, item.start, body_l)
+        };
+        let parent = self.push(m, item.start, item.end, None, Cond::Impl(trait_name, self_name), refs);
+        if is_trait {
+            return;
+        }
+        for sub in src.items(body_l, body_r) {
+            let cond = match (sub.kind, &sub.name) {
+                (ItemKind::Fn, Some(name)) | (ItemKind::Const, Some(name)) => Cond::Method(name.clone()),
+                _ => Cond::Always,
+            };
+            let refs = idents(src, sub.start, sub.end);
+            self.push(m, sub.start, sub.end, Some(parent), cond, refs);
+        }
+    }
+
+    fn add_item(&mut self, m: usize, item: &Item, root: bool) {
+        let src = &self.module(m).src;
+        let refs = idents(src, item.start, item.end);
+        let cond = match item.kind {
+            ItemKind::Use | ItemKind::InnerAttr => return,
+            ItemKind::Impl => return self.add_impl(m, item),
+            _ if root => Cond::Root,
+            ItemKind::Trait => {
+                let methods = match item.body {
+                    Some((l, r)) => src
+                        .items(l, r)
+                        .into_iter()
+                        .filter_map(|sub| sub.name)
+                        .collect(),
+                    None => vec![],
+                };
+                Cond::Trait(item.name.clone().unwrap_or_default(), methods)
+            }
+            ItemKind::MacroCall => {
+                let group = src.matching(item.end - 1 - src.is(item.end - 1, ";") as usize);
+                let fresh = src
+                    .ident(group + 1)
+                    .filter(|s| !PRIMITIVES.contains(s) && !self.defined.contains(*s))
+                    .map(|s| s.to_string());
+                if let Some(fresh) = &fresh {
+                    self.defined.insert(fresh.clone());
+                }
+                let args = idents(src, group + 1, item.end);
+                Cond::Macro(fresh, args)
+            }
+            _ => match &item.name {
+                Some(name) => Cond::Named(name.clone()),
+                None => Cond::Root,
+            },
+        };
+        self.push(m, item.start, item.end, None, cond, refs);
+    }
+
+    fn check(&self, node: &Node) -> bool {
+        if let Some(p) = node.parent {
+            if !self.nodes[p].alive {
+                return false;
+            }
+        }
+        let live = |name: &String| self.live.contains(name);
+        match &node.cond {
+            Cond::Root | Cond::Always => true,
+            Cond::Named(name) | Cond::Method(name) => live(name),
+            Cond::Trait(name, methods) => live(name) || methods.iter().any(live),
+            Cond::Impl(t, s) => {
+                (!self.is_local(t) || live(t.as_ref().unwrap()))
+                    && (!self.is_local(s) || live(s.as_ref().unwrap()))
+            }
+            Cond::Macro(fresh, args) => match fresh {
+                Some(fresh) => live(fresh),
+                None => args
+                    .iter()
+                    .filter(|a| self.defined.contains(*a))
+                    .all(live),
+            },
+        }
+    }
+
+    fn run(&mut self) {
+        loop {
+            let mut changed = false;
+            for i in 0..self.nodes.len() {
+                if !self.nodes[i].alive && self.check(&self.nodes[i]) {
+                    self.nodes[i].alive = true;
+                    let refs = std::mem::take(&mut self.nodes[i].refs);
+                    self.live.extend(refs.iter().cloned());
+                    self.nodes[i].refs = refs;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+}
+
+///
+/// Item level dead code elimination
+///
+/// Names are resolved by identifier only, regardless of paths and scopes, so an item is
+/// kept as long as some alive item mentions its name. Trait impls are kept whenever both
+/// of the trait and the self type are kept, while associated functions of inherent impls
+/// are kept only if their names are mentioned.
+///
+/// Return edits for each module (None for removed module) and for main.rs
+///
+pub fn shake(krate: &Crate, reachable: &[bool], entries: &[usize]) -> (Vec<Option<Vec<Edit>>>, Vec<Edit>) {
+    let n = krate.modules.len();
+    let mut shaker = Shaker {
+        krate,
+        nodes: Vec::new(),
+        defined: HashSet::new(),
+        live: HashSet::new(),
+    };
+    for m in (0..=n).filter(|&m| m == n || reachable[m]) {
+        for item in &shaker.module(m).items {
+            if item.kind != ItemKind::MacroCall && item.kind != ItemKind::Impl {
+                shaker.defined.extend(item.name.clone());
+            }
+        }
+    }
+    for m in (0..=n).filter(|&m| m == n || reachable[m]) {
+        for item in &shaker.module(m).items {
+            if krate.is_enabled(item) {
+                shaker.add_item(m, item, m == n || entries.contains(&m));
+            }
+        }
+    }
+    shaker.run();
+
+    let mut refs: Vec<HashSet<&str>> = vec![HashSet::new(); n + 1];
+    let mut kept = vec![false; n + 1];
+    for node in shaker.nodes.iter().filter(|x| x.alive) {
+        kept[node.module] = true;
+        refs[node.module].extend(node.refs.iter().map(|s| s.as_str()));
+    }
+    kept[n] = true;
+    let live_traits: HashSet<&str> = shaker
+        .nodes
+        .iter()
+        .filter(|x| x.alive)
+        .filter_map(|x| match &x.cond {
+            Cond::Trait(name, _) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let mut edits: Vec<Vec<Edit>> = (0..=n).map(|_| Vec::new()).collect();
+    for node in shaker.nodes.iter().filter(|x| !x.alive) {
+        let dropped_with_parent = node.parent.map(|p| !shaker.nodes[p].alive).unwrap_or(false);
+        if !dropped_with_parent {
+            edits[node.module].push(Edit {
+                from: node.start,
+                to: node.end,
+                text: None,
+            });
+        }
+    }
+    for m in (0..=n).filter(|&m| kept[m]) {
+        let module = shaker.module(m);
+        let src = &module.src;
+        for item in &module.items {
+            if item.kind != ItemKind::Use || !krate.is_enabled(item) {
+                continue;
+            }
+            let vis = src.visibility(item);
+            let mut lines: Vec<String> = Vec::new();
+            for mut path in src.use_paths(item) {
+                if path.segments[0] == krate.name {
+                    path.segments[0] = "crate".to_string();
+                }
+                let binding = path.binding().map(|s| s.to_string());
+                let mentioned = binding.as_ref().map(|b| refs[m].contains(b.as_str())).unwrap_or(false);
+                let keep = if path.segments[0] == "crate" {
+                    let target = path
+                        .segments
+                        .get(1)
+                        .and_then(|x| krate.module_index(x))
+                        .map(|x| kept[x])
+                        .unwrap_or(false);
+                    let is_trait = binding.as_ref().map(|b| live_traits.contains(b.as_str())).unwrap_or(false);
+                    target && (path.glob || mentioned || is_trait)
+                } else if path.segments[0] == "self" || path.segments[0] == "super" {
+                    path.glob || mentioned
+                } else {
+                    path.glob || mentioned || binding.as_ref().map(|b| STD_TRAITS.contains(&b.as_str())).unwrap_or(false)
+                };
+                let line = path.render(&vis);
+                if keep && !lines.contains(&line) {
+                    lines.push(line);
+                }
+            }
+            let text = if lines.is_empty() {
+                None
+            } else {
+                let mut text = String::new();
+                for attr in &item.attrs {
+                    text.push_str(attr);
+                    text.push('\n');
+                }
+                text.push_str(&lines.join("\n"));
+                Some(text)
+            };
+            edits[m].push(Edit {
+                from: item.start,
+                to: item.end,
+                text,
+            });
+        }
+        for item in &module.items {
+            if !krate.is_enabled(item) || item.kind == ItemKind::InnerAttr {
+                edits[m].push(Edit {
+                    from: item.start,
+                    to: item.end,
+                    text: None,
+                });
+            }
+        }
+    }
+    let main_edits = edits.pop().unwrap();
+    let module_edits = edits
+        .into_iter()
+        .enumerate()
+        .map(|(m, e)| if kept[m] && reachable[m] { Some(e) } else { None })
+        .collect();
+    (module_edits, main_edits)
+}
@@ -0,0 +1,469 @@
+use crate::{
+    cfg,
+    lexer::{tokenize, Kind, Token},
+};
+
+///
+/// Tokenized source file, `code` indexes the tokens that are not comments
+///
+pub struct Source {
+    pub text: String,
+    pub tokens: Vec<Token>,
+    pub code: Vec<usize>,
+    matching: Vec<usize>,
+}
+
+impl Source {
+    pub fn new(text: String) -> Self {
+        let tokens = tokenize(&text);
+        let code: Vec<usize> = (0..tokens.len())
+            .filter(|&i| tokens[i].kind != Kind::Comment)
+            .collect();
+        let mut matching = vec![usize::MAX; code.len()];
+        let mut stack = Vec::new();
+        for (i, &t) in code.iter().enumerate() {
+            match tokens[t].kind {
+                Kind::Open => stack.push(i),
+                Kind::Close => {
+                    if let Some(open) = stack.pop() {
+                        matching[open] = i;
+                        matching[i] = open;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            text,
+            tokens,
+            code,
+            matching,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+
+    pub fn token(&self, i: usize) -> &Token {
+        &self.tokens[self.code[i]]
+    }
+
+    pub fn kind(&self, i: usize) -> Option<Kind> {
+        if i < self.code.len() {
+            Some(self.token(i).kind)
+        } else {
+            None
+        }
+    }
+
+    pub fn str(&self, i: usize) -> &str {
+        if i < self.code.len() {
+            self.token(i).text(&self.text)
+        } else {
+            ""
+        }
+    }
+
+    pub fn is(&self, i: usize, s: &str) -> bool {
+        self.str(i) == s
+    }
+
+    pub fn ident(&self, i: usize) -> Option<&str> {
+        if self.kind(i) == Some(Kind::Ident) {
+            Some(self.str(i))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// whether a `::` starts at i
+    ///
+    pub fn is_path_sep(&self, i: usize) -> bool {
+        self.is(i, ":") && self.is(i + 1, ":") && self.token(i).end == self.token(i + 1).start
+    }
+
+    ///
+    /// index of the token closing the group opened at i
+    ///
+    pub fn matching(&self, i: usize) -> usize {
+        self.matching[i]
+    }
+
+    ///
+    /// source text covering code tokens [l, r)
+    ///
+    pub fn slice(&self, l: usize, r: usize) -> &str {
+        if l >= r {
+            return "";
+        }
+        &self.text[self.token(l).start..self.token(r - 1).end]
+    }
+
+    ///
+    /// range of tokens (comments included) covering code tokens [l, r)
+    ///
+    pub fn token_range(&self, l: usize, r: usize) -> (usize, usize) {
+        if l >= r {
+            let t = if l < self.code.len() {
+                self.code[l]
+            } else {
+                self.tokens.len()
+            };
+            return (t, t);
+        }
+        (self.code[l], self.code[r - 1] + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    InnerAttr,
+    Use,
+    Mod,
+    Fn,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    Impl,
+    Const,
+    Static,
+    Type,
+    MacroRules,
+    MacroCall,
+    ExternCrate,
+    Other,
+}
+
+///
+/// A top level item, all indices are code token indices
+///
+#[derive(Clone, Debug)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub name: Option<String>,
+    pub start: usize,
+    pub end: usize,
+    ///
+    /// first token after attributes
+    ///
+    pub head: usize,
+    ///
+    /// token after the item keyword, `impl`/`fn`/`struct`...
+    ///
+    pub keyword: usize,
+    ///
+    /// content of the brace group for mod, trait and impl
+    ///
+    pub body: Option<(usize, usize)>,
+    pub attrs: Vec<String>,
+}
+
+impl Item {
+    pub fn is_test(&self) -> bool {
+        self.attrs
+            .iter()
+            .map(|a| normalize(a))
+            .any(|a| a == "#[test]" || a == "#[cfg(test)]")
+    }
+
+    ///
+    /// false for tests and items whose cfg is known to be false with the given features
+    ///
+    pub fn is_enabled(&self, features: &[String]) -> bool {
+        !self.is_test() && self.attrs.iter().all(|a| cfg::eval(a, features) != Some(false))
+    }
+}
+
+///
+/// A flattened use tree leaf, `use a::{b, c as d}` produce `a::b` and `a::c as d`
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsePath {
+    pub segments: Vec<String>,
+    pub alias: Option<String>,
+    pub glob: bool,
+}
+
+impl UsePath {
+    ///
+    /// the name introduced into scope, None for glob import
+    ///
+    pub fn binding(&self) -> Option<&str> {
+        if self.glob {
+            return None;
+        }
+        if let Some(alias) = &self.alias {
+            return Some(alias.as_str());
+        }
+        self.path().last().map(|s| s.as_str())
+    }
+
+    ///
+    /// segments with trailing `self` removed
+    ///
+    pub fn path(&self) -> &[String] {
+        match self.segments.last() {
+            Some(last) if last == "self" && self.segments.len() > 1 => {
+                &self.segments[..self.segments.len() - 1]
+            }
+            _ => &self.segments,
+        }
+    }
+
+    pub fn render(&self, vis: &str) -> String {
+        let mut s = String::new();
+        s.push_str(vis);
+        s.push_str("use ");
+        s.push_str(&self.path().join("::"));
+        if self.glob {
+            s.push_str("::*");
+        }
+        if let Some(alias) = &self.alias {
+            s.push_str(" as ");
+            s.push_str(alias);
+        }
+        s.push(';');
+        s
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+const QUALIFIERS: [&str; 4] = ["unsafe", "async", "default", "extern"];
+
+impl Source {
+    ///
+    /// index after the first `;` on depth 0, or after the first brace group if `brace` is set
+    ///
+    fn scan_end(&self, mut i: usize, to: usize, brace: bool) -> usize {
+        while i < to {
+            match self.kind(i) {
+                Some(Kind::Open) => {
+                    let close = self.matching(i);
+                    if brace && self.is(i, "{") {
+                        return close + 1;
+                    }
+                    i = close + 1;
+                }
+                Some(Kind::Punct) if self.is(i, ";") => return i + 1,
+                _ => i += 1,
+            }
+        }
+        to
+    }
+
+    fn brace_body(&self, from: usize, end: usize) -> Option<(usize, usize)> {
+        if end > from && self.is(end - 1, "}") {
+            let open = self.matching(end - 1);
+            if open >= from {
+                return Some((open + 1, end - 1));
+            }
+        }
+        None
+    }
+
+    ///
+    /// Split code tokens [from, to) into items
+    ///
+    pub fn items(&self, from: usize, to: usize) -> Vec<Item> {
+        let mut res = Vec::new();
+        let mut i = from;
+        while i < to {
+            let start = i;
+            if self.is(i, ";") {
+                i += 1;
+                continue;
+            }
+            if self.is(i, "#") && self.is(i + 1, "!") && self.is(i + 2, "[") {
+                let end = self.matching(i + 2) + 1;
+                res.push(Item {
+                    kind: ItemKind::InnerAttr,
+                    name: None,
+                    start,
+                    end,
+                    head: start,
+                    keyword: end,
+                    body: None,
+                    attrs: vec![],
+                });
+                i = end;
+                continue;
+            }
+            let mut attrs = Vec::new();
+            while self.is(i, "#") && self.is(i + 1, "[") {
+                let close = self.matching(i + 1);
+                attrs.push(self.slice(i, close + 1).to_string());
+                i = close + 1;
+            }
+            let head = i;
+            if self.is(i, "pub") {
+                i += 1;
+                if self.is(i, "(") {
+                    i = self.matching(i) + 1;
+                }
+            }
+            loop {
+                match self.ident(i) {
+                    Some("extern") if self.kind(i + 1) == Some(Kind::Literal) => i += 2,
+                    Some("const") if matches!(self.ident(i + 1), Some("fn") | Some("unsafe") | Some("async") | Some("extern")) => i += 1,
+                    Some(q) if QUALIFIERS.contains(&q) && self.ident(i + 1).is_some() && !self.is(i + 1, "crate") => i += 1,
+                    _ => break,
+                }
+            }
+            let word = self.ident(i).unwrap_or("");
+            let keyword = i + 1;
+            let next_name = self.ident(i + 1).map(|s| s.to_string());
+            let (kind, name, end) = match word {
+                "use" => (ItemKind::Use, None, self.scan_end(i, to, false)),
+                "mod" => (ItemKind::Mod, next_name, self.scan_end(i, to, true)),
+                "fn" => (ItemKind::Fn, next_name, self.scan_end(i, to, true)),
+                "struct" => (ItemKind::Struct, next_name, self.scan_end(i, to, true)),
+                "enum" => (ItemKind::Enum, next_name, self.scan_end(i, to, true)),
+                "union" => (ItemKind::Union, next_name, self.scan_end(i, to, true)),
+                "trait" => (ItemKind::Trait, next_name, self.scan_end(i, to, true)),
+                "impl" => (ItemKind::Impl, None, self.scan_end(i, to, true)),
+                "const" => (ItemKind::Const, next_name, self.scan_end(i, to, false)),
+                "static" => {
+                    let name = if self.is(i + 1, "mut") {
+                        self.ident(i + 2).map(|s| s.to_string())
+                    } else {
+                        next_name
+                    };
+                    (ItemKind::Static, name, self.scan_end(i, to, false))
+                }
+                "type" => (ItemKind::Type, next_name, self.scan_end(i, to, false)),
+                "extern" if self.is(i + 1, "crate") => (
+                    ItemKind::ExternCrate,
+                    self.ident(i + 2).map(|s| s.to_string()),
+                    self.scan_end(i, to, false),
+                ),
+                "macro_rules" if self.is(i + 1, "!") => {
+                    let group = i + 3;
+                    let mut end = self.matching(group) + 1;
+                    if self.is(end, ";") {
+                        end += 1;
+                    }
+                    (ItemKind::MacroRules, self.ident(i + 2).map(|s| s.to_string()), end)
+                }
+                _ => {
+                    let mut j = i;
+                    while self.ident(j).is_some() && self.is_path_sep(j + 1) {
+                        j += 3;
+                    }
+                    if self.ident(j).is_some() && self.is(j + 1, "!") && self.kind(j + 2) == Some(Kind::Open) {
+                        let mut end = self.matching(j + 2) + 1;
+                        if self.is(end, ";") {
+                            end += 1;
+                        }
+                        (ItemKind::MacroCall, Some(self.str(j).to_string()), end)
+                    } else {
+                        (ItemKind::Other, None, self.scan_end(i, to, true))
+                    }
+                }
+            };
+            let end = end.max(start + 1).min(to);
+            let body = match kind {
+                ItemKind::Mod | ItemKind::Trait | ItemKind::Impl => self.brace_body(i, end),
+                _ => None,
+            };
+            res.push(Item {
+                kind,
+                name,
+                start,
+                end,
+                head,
+                keyword,
+                body,
+                attrs,
+            });
+            i = end;
+        }
+        res
+    }
+
+    ///
+    /// visibility text of an item, e.g. `pub(crate) `
+    ///
+    pub fn visibility(&self, item: &Item) -> String {
+        if self.is(item.head, "pub") {
+            let mut end = item.head + 1;
+            if self.is(end, "(") {
+                end = self.matching(end) + 1;
+            }
+            normalize(self.slice(item.head, end)) + " "
+        } else {
+            String::new()
+        }
+    }
+
+    ///
+    /// flatten the use tree of a use item
+    ///
+    pub fn use_paths(&self, item: &Item) -> Vec<UsePath> {
+        let mut res = Vec::new();
+        let mut i = item.head;
+        while !self.is(i, "use") {
+            i += 1;
+        }
+        self.use_tree(i + 1, &[], &mut res);
+        res
+    }
+
+    fn use_tree(&self, mut i: usize, prefix: &[String], out: &mut Vec<UsePath>) -> usize {
+        let mut segments = prefix.to_vec();
+        let mut glob = false;
+        if self.is_path_sep(i) {
+            i += 2;
+        }
+        loop {
+            if self.is(i, "{") {
+                let close = self.matching(i);
+                let mut j = i + 1;
+                while j < close {
+                    j = self.use_tree(j, &segments, out);
+                    if self.is(j, ",") {
+                        j += 1;
+                    }
+                }
+                return close + 1;
+            }
+            if self.is(i, "*") {
+                glob = true;
+                i += 1;
+                break;
+            }
+            match self.ident(i) {
+                Some(seg) => {
+                    segments.push(seg.to_string());
+                    i += 1;
+                    if self.is_path_sep(i) {
+                        i += 2;
+                    } else {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+        let mut alias = None;
+        if self.is(i, "as") {
+            alias = self.ident(i + 1).map(|s| s.to_string());
+            i += 2;
+        }
+        out.push(UsePath {
+            segments,
+            alias,
+            glob,
+        });
+        i
+    }
+}
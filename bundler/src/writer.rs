@@ -0,0 +1,102 @@
+///
+/// Output buffer that re-indents code and squeezes blank lines
+///
+/// Text passed through `code` has its trailing spaces trimmed, runs of blank lines
+/// collapsed into one and each line prefixed by current indentation, while text passed
+/// through `raw` (string literals) is copied verbatim.
+///
+pub struct Writer {
+    out: String,
+    indent: usize,
+    newlines: usize,
+    spaces: String,
+}
+
+const INDENT: &str = "    ";
+
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Self {
+            out: String::new(),
+            indent: 0,
+            newlines: 0,
+            spaces: String::new(),
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.newlines > 0 {
+            if !self.out.is_empty() {
+                for _ in 0..self.newlines.min(2) {
+                    self.out.push('\n');
+                }
+            }
+            for _ in 0..self.indent {
+                self.out.push_str(INDENT);
+            }
+            self.newlines = 0;
+        }
+        self.out.push_str(&self.spaces);
+        self.spaces.clear();
+    }
+
+    pub fn code(&mut self, s: &str) {
+        for c in s.chars() {
+            if c == '\n' {
+                self.spaces.clear();
+                self.newlines += 1;
+            } else if c.is_whitespace() {
+                self.spaces.push(c);
+            } else {
+                if self.newlines == 0 && self.spaces.is_empty() && self.out.is_empty() {
+                    self.newlines = 1;
+                }
+                self.flush();
+                self.out.push(c);
+            }
+        }
+    }
+
+    pub fn raw(&mut self, s: &str) {
+        self.flush();
+        self.out.push_str(s);
+    }
+
+    ///
+    /// start a new line unless already at the beginning of one
+    ///
+    pub fn newline(&mut self) {
+        self.spaces.clear();
+        self.newlines = self.newlines.max(1);
+    }
+
+    pub fn line(&mut self, s: &str) {
+        self.newline();
+        self.code(s);
+        self.newline();
+    }
+
+    pub fn blank_line(&mut self) {
+        self.spaces.clear();
+        self.newlines = 2;
+    }
+
+    pub fn indent(&mut self) {
+        self.indent += 1;
+    }
+
+    pub fn dedent(&mut self) {
+        self.indent -= 1;
+    }
+
+    pub fn finish(mut self) -> String {
+        self.out.push('\n');
+        self.out
+    }
+}
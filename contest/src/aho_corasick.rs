@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use crate::{
+    algebraic_structure::Monoid,
+    trie::{Trie, NIL, ROOT},
+};
+
+///
+/// Aho-Corasick automaton over characters in [0, C) built on a trie
+///
+/// Insert every pattern with a weight, then `build` once. After that the transitions of
+/// the trie are the complete goto function, and the sum of a state is the sum of weights of
+/// all patterns that are suffixes of it, from the longest to the shortest
+///
+/// # Example
+///
+/// Count strings of length n over {a, b} avoiding "aa" and "bab"
+///
+/// ```ignore
+/// let mut ac = AhoCorasick::<u64, 2>::new(0);
+/// ac.insert(vec![0, 0], 1);
+/// ac.insert(vec![1, 0, 1], 1);
+/// ac.build();
+/// let mut dp = vec![0u64; ac.size()];
+/// dp[ac.root()] = 1;
+/// for _ in 0..n {
+///     let mut next = vec![0u64; ac.size()];
+///     for &s in ac.bfs_order() {
+///         for c in 0..2 {
+///             let t = ac.goto(s, c);
+///             if ac.sum(t) == 0 {
+///                 next[t] += dp[s];
+///             }
+///         }
+///     }
+///     dp = next;
+/// }
+/// ```
+///
+pub struct AhoCorasick<S: Monoid, const C: usize> {
+    trie: Trie<S, C>,
+    fail: Vec<usize>,
+    order: Vec<usize>,
+}
+
+impl<S: Monoid, const C: usize> AhoCorasick<S, C> {
+    pub fn new(estimate_cap: usize) -> Self {
+        Self {
+            trie: Trie::new(estimate_cap),
+            fail: vec![],
+            order: vec![],
+        }
+    }
+
+    ///
+    /// Add a pattern with its weight, return the state of the pattern
+    ///
+    pub fn insert(&mut self, pattern: impl IntoIterator<Item = usize>, weight: S) -> usize {
+        let state = self.trie.insert(&mut pattern.into_iter());
+        let row = self.trie.node(state);
+        row.sum = row.sum + weight;
+        state
+    }
+
+    ///
+    /// Compute fail links in BFS order, turn the trie into the goto function and
+    /// aggregate the sums along fail links, no more insertion is allowed afterwards
+    ///
+    pub fn build(&mut self) {
+        let n = self.trie.size();
+        self.fail = vec![ROOT; n];
+        self.fail[NIL] = NIL;
+        self.order = Vec::with_capacity(n - 1);
+        let mut dq = VecDeque::new();
+        dq.push_back(ROOT);
+        while let Some(u) = dq.pop_front() {
+            self.order.push(u);
+            let fail_u = self.fail[u];
+            for c in 0..C {
+                let v = self.trie.row(u).adj[c];
+                let goto = if u == ROOT {
+                    ROOT
+                } else {
+                    self.trie.row(fail_u).adj[c]
+                };
+                if v == NIL {
+                    self.trie.node(u).adj[c] = goto;
+                } else {
+                    self.fail[v] = goto;
+                    let inherit = self.trie.row(goto).sum;
+                    let row = self.trie.node(v);
+                    row.sum = row.sum + inherit;
+                    dq.push_back(v);
+                }
+            }
+        }
+    }
+
+    pub fn root(&self) -> usize {
+        ROOT
+    }
+
+    ///
+    /// Number of states including the unused NIL
+    ///
+    pub fn size(&self) -> usize {
+        self.trie.size()
+    }
+
+    ///
+    /// States from ROOT in BFS order, a state always goes after its fail link
+    ///
+    pub fn bfs_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    pub fn goto(&self, state: usize, c: usize) -> usize {
+        self.trie.row(state).adj[c]
+    }
+
+    pub fn fail(&self, state: usize) -> usize {
+        self.fail[state]
+    }
+
+    ///
+    /// Sum of weights of the patterns that are suffixes of the state
+    ///
+    pub fn sum(&self, state: usize) -> S {
+        self.trie.row(state).sum
+    }
+
+    ///
+    /// Feed text from ROOT, the i-th item is the state after reading text[..=i]
+    ///
+    pub fn walk<'a>(&'a self, text: impl IntoIterator<Item = usize> + 'a) -> impl Iterator<Item = usize> + 'a {
+        text.into_iter().scan(ROOT, move |state, c| {
+            *state = self.goto(*state, c);
+            Some(*state)
+        })
+    }
+
+    ///
+    /// Sum of the weights of all pattern occurrences in text
+    ///
+    pub fn total(&self, text: impl IntoIterator<Item = usize>) -> S {
+        self.walk(text).fold(S::zero(), |acc, state| acc + self.sum(state))
+    }
+
+    ///
+    /// Number of occurrences of every state as a substring of text, index it by the
+    /// state returned from `insert` to get the occurrences of a pattern
+    ///
+    pub fn occurrences(&self, text: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut res = vec![0; self.size()];
+        for state in self.walk(text) {
+            res[state] += 1;
+        }
+        for &state in self.order.iter().rev() {
+            if state != ROOT {
+                res[self.fail[state]] += res[state];
+            }
+        }
+        res
+    }
+}
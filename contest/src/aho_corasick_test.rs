@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::{aho_corasick::AhoCorasick, rand::Rng, string::kmp_search};
+
+    fn random_string(rng: &mut Rng, n: usize, alphabet: u64) -> Vec<usize> {
+        (0..n).map(|_| rng.limit_u64(alphabet) as usize).collect()
+    }
+
+    #[test]
+    fn test_aho_corasick_matching() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let k = rng.range_usize(1, 6);
+            let patterns: Vec<Vec<usize>> = (0..k)
+                .map(|_| {
+                    let m = rng.range_usize(1, 5);
+                    random_string(&mut rng, m, 3)
+                })
+                .collect();
+            let n = rng.range_usize(0, 40);
+            let text = random_string(&mut rng, n, 3);
+            let mut ac = AhoCorasick::<u64, 3>::new(0);
+            let states: Vec<usize> = patterns
+                .iter()
+                .enumerate()
+                .map(|(i, p)| ac.insert(p.iter().copied(), 1 << i))
+                .collect();
+            ac.build();
+            let occ = ac.occurrences(text.iter().copied());
+            for (p, &state) in patterns.iter().zip(&states) {
+                assert_eq!(kmp_search(p, &text).len(), occ[state]);
+            }
+            for (i, state) in ac.walk(text.iter().copied()).enumerate() {
+                let expect: u64 = patterns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| text[..=i].ends_with(p))
+                    .map(|(j, _)| 1 << j)
+                    .sum();
+                assert_eq!(expect, ac.sum(state));
+            }
+        }
+    }
+
+    #[test]
+    fn test_aho_corasick_dp() {
+        let patterns = vec![vec![0, 0], vec![1, 0, 1]];
+        let mut ac = AhoCorasick::<u64, 2>::new(0);
+        for p in &patterns {
+            ac.insert(p.iter().copied(), 1);
+        }
+        ac.build();
+        let mut dp = vec![0u64; ac.size()];
+        dp[ac.root()] = 1;
+        for n in 1..=12 {
+            let mut next = vec![0u64; ac.size()];
+            for &s in ac.bfs_order() {
+                for c in 0..2 {
+                    let t = ac.goto(s, c);
+                    if ac.sum(t) == 0 {
+                        next[t] += dp[s];
+                    }
+                }
+            }
+            dp = next;
+            let expect = (0..1usize << n)
+                .filter(|mask| {
+                    let s: Vec<usize> = (0..n).map(|i| mask >> i & 1).collect();
+                    patterns.iter().all(|p| kmp_search(p, &s).is_empty())
+                })
+                .count() as u64;
+            assert_eq!(expect, dp.iter().sum::<u64>());
+        }
+    }
+}
@@ -0,0 +1,167 @@
+///
+/// Judge whether the output of a solution is acceptable
+///
+/// Any closure `Fn(input, expect, actual) -> Result<(), String>` is a checker as well,
+/// which is the way to write a special judge, `expect` is the output of brute force.
+///
+/// # Example
+///
+/// ```not_run
+/// let checker = |_input: &str, expect: &str, actual: &str| {
+///     if expect.trim() == "-1" || actual.trim() != "-1" {
+///         TokenChecker::exact().check("", expect, actual)
+///     } else {
+///         Err("answer exists but -1 found".to_string())
+///     }
+/// };
+/// ```
+///
+pub trait Checker: Send + Sync {
+    ///
+    /// Err with a message describing the first difference
+    ///
+    fn check(&self, input: &str, expect: &str, actual: &str) -> Result<(), String>;
+}
+
+impl<F> Checker for F
+where
+    F: Fn(&str, &str, &str) -> Result<(), String> + Send + Sync,
+{
+    fn check(&self, input: &str, expect: &str, actual: &str) -> Result<(), String> {
+        self(input, expect, actual)
+    }
+}
+
+///
+/// Token with its 1-based line and column
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+pub fn tokenize(s: &str) -> Vec<Token<'_>> {
+    let mut res = Vec::new();
+    for (i, line) in s.lines().enumerate() {
+        let mut rest = line;
+        while let Some(begin) = rest.find(|c: char| !c.is_ascii_whitespace()) {
+            let end = rest[begin..]
+                .find(|c: char| c.is_ascii_whitespace())
+                .map(|x| x + begin)
+                .unwrap_or(rest.len());
+            res.push(Token {
+                text: &rest[begin..end],
+                line: i + 1,
+                column: line.len() - rest.len() + begin + 1,
+            });
+            rest = &rest[end..];
+        }
+    }
+    res
+}
+
+///
+/// Compare tokens one by one
+///
+/// Floats are compared with tolerance if either `abs_eps` or `rel_eps` is set, and
+/// `YES`/`NO` are accepted in any case if `yes_no_any_case` is set.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenChecker {
+    pub abs_eps: Option<f64>,
+    pub rel_eps: Option<f64>,
+    pub yes_no_any_case: bool,
+}
+
+impl TokenChecker {
+    pub fn exact() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Accept if either absolute or relative error is at most eps
+    ///
+    pub fn float(abs_eps: f64, rel_eps: f64) -> Self {
+        Self {
+            abs_eps: Some(abs_eps),
+            rel_eps: Some(rel_eps),
+            ..Self::default()
+        }
+    }
+
+    pub fn yes_no() -> Self {
+        Self {
+            yes_no_any_case: true,
+            ..Self::default()
+        }
+    }
+
+    fn is_yes_no(s: &str) -> bool {
+        s.eq_ignore_ascii_case("yes") || s.eq_ignore_ascii_case("no")
+    }
+
+    fn same_float(&self, expect: &str, actual: &str) -> bool {
+        if self.abs_eps.is_none() && self.rel_eps.is_none() {
+            return false;
+        }
+        let (e, a) = match (expect.parse::<f64>(), actual.parse::<f64>()) {
+            (Ok(e), Ok(a)) => (e, a),
+            _ => return false,
+        };
+        if e.is_nan() || a.is_nan() {
+            return false;
+        }
+        let err = (e - a).abs();
+        self.abs_eps.map(|eps| err <= eps).unwrap_or(false)
+            || self.rel_eps.map(|eps| err <= eps * e.abs()).unwrap_or(false)
+    }
+
+    pub fn same_token(&self, expect: &str, actual: &str) -> bool {
+        expect == actual
+            || (self.yes_no_any_case && Self::is_yes_no(expect) && expect.eq_ignore_ascii_case(actual))
+            || self.same_float(expect, actual)
+    }
+}
+
+impl Checker for TokenChecker {
+    fn check(&self, _input: &str, expect: &str, actual: &str) -> Result<(), String> {
+        let expect = tokenize(expect);
+        let actual = tokenize(actual);
+        for (i, (e, a)) in expect.iter().zip(actual.iter()).enumerate() {
+            if !self.same_token(e.text, a.text) {
+                return Err(format!(
+                    "token {} differs at line {} column {}, expect `{}` but found `{}`",
+                    i + 1,
+                    a.line,
+                    a.column,
+                    e.text,
+                    a.text
+                ));
+            }
+        }
+        if expect.len() > actual.len() {
+            let e = &expect[actual.len()];
+            return Err(format!(
+                "expect {} tokens but found {}, token {} `{}` is missing",
+                expect.len(),
+                actual.len(),
+                actual.len() + 1,
+                e.text
+            ));
+        }
+        if expect.len() < actual.len() {
+            let a = &actual[expect.len()];
+            return Err(format!(
+                "expect {} tokens but found {}, extra token `{}` at line {} column {}",
+                expect.len(),
+                actual.len(),
+                a.text,
+                a.line,
+                a.column
+            ));
+        }
+        Ok(())
+    }
+}
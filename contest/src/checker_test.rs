@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::checker::*;
+
+    #[test]
+    fn test_exact() {
+        let c = TokenChecker::exact();
+        assert!(c.check("", "1 2\n3", " 1\n2 3 \n").is_ok());
+        assert_eq!(
+            Err("token 3 differs at line 2 column 3, expect `3` but found `4`".to_string()),
+            c.check("", "1 2 3", "1 2\n  4")
+        );
+        assert_eq!(
+            Err("expect 3 tokens but found 2, token 3 `3` is missing".to_string()),
+            c.check("", "1 2 3", "1 2")
+        );
+        assert_eq!(
+            Err("expect 1 tokens but found 2, extra token `2` at line 1 column 3".to_string()),
+            c.check("", "1", "1 2")
+        );
+        assert!(c.check("", "YES", "yes").is_err());
+    }
+
+    #[test]
+    fn test_float() {
+        let c = TokenChecker::float(1e-6, 1e-6);
+        assert!(c.check("", "1.0000000 abc", "1.0000005 abc").is_ok());
+        assert!(c.check("", "1000000000", "1000000100").is_ok());
+        assert!(c.check("", "1.0", "1.00001").is_err());
+        assert!(c.check("", "1.0", "nan").is_err());
+        let abs = TokenChecker {
+            abs_eps: Some(1e-3),
+            ..TokenChecker::default()
+        };
+        assert!(abs.check("", "1000000000", "1000000100").is_err());
+        assert!(abs.check("", "0.5", "0.5009").is_ok());
+    }
+
+    #[test]
+    fn test_yes_no() {
+        let c = TokenChecker::yes_no();
+        assert!(c.check("", "YES\nNO", "yEs\nno").is_ok());
+        assert!(c.check("", "Alice", "alice").is_err());
+    }
+
+    #[test]
+    fn test_special_judge() {
+        //any permutation of expect is accepted
+        let judge = |_: &str, expect: &str, actual: &str| {
+            let mut e: Vec<_> = tokenize(expect).into_iter().map(|t| t.text).collect();
+            let mut a: Vec<_> = tokenize(actual).into_iter().map(|t| t.text).collect();
+            e.sort();
+            a.sort();
+            if e == a {
+                Ok(())
+            } else {
+                Err("not a permutation".to_string())
+            }
+        };
+        let checker: &dyn Checker = &judge;
+        assert!(checker.check("", "1 2 3", "3 1 2").is_ok());
+        assert!(checker.check("", "1 2 3", "3 1 1").is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    geometry2d::{sign, Containment, Point},
+    num_float::float,
+    num_number::Number,
+    num_real::Real,
+    rand::Rng,
+    shuffle::shuffle_with,
+};
+
+type P = Point<float>;
+
+fn f(x: f64) -> float {
+    x.into()
+}
+
+fn len(p: &P) -> float {
+    p.norm2().sqrt()
+}
+
+///
+/// Signed angle from p to q in (-pi, pi]
+///
+fn angle(p: &P, q: &P) -> float {
+    f(p.cross(q).as_f64().atan2(p.dot(q).as_f64()))
+}
+
+///
+/// Circle with float center and radius, every comparison is done by `geometry2d::sign`
+/// with tolerance EPS
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Circle {
+    pub c: P,
+    pub r: float,
+}
+
+impl Circle {
+    pub fn new(c: P, r: float) -> Self {
+        Self { c, r }
+    }
+
+    pub fn area(&self) -> float {
+        float::PI * self.r * self.r
+    }
+
+    pub fn contains(&self, p: &P) -> Containment {
+        match sign(self.r - len(&(*p - self.c))) {
+            1 => Containment::Inside,
+            0 => Containment::OnBoundary,
+            _ => Containment::Outside,
+        }
+    }
+
+    ///
+    /// Circle through a, b and c, None if they are collinear
+    ///
+    pub fn circumcircle(a: &P, b: &P, c: &P) -> Option<Self> {
+        let (ab, ac) = (*b - *a, *c - *a);
+        let cross = ab.cross(&ac);
+        if sign(cross) == 0 {
+            return None;
+        }
+        let center = *a + (ab * ac.norm2() - ac * ab.norm2()).rotate90() / (cross * f(2.0));
+        Some(Self::new(center, len(&(center - *a))))
+    }
+
+    ///
+    /// Intersection points with another circle, two equal points if they touch, none if
+    /// the circles are identical
+    ///
+    pub fn intersect_circle(&self, rhs: &Self) -> Vec<P> {
+        let d = rhs.c - self.c;
+        let d2 = d.norm2();
+        if sign(d2) == 0 {
+            return vec![];
+        }
+        let (sum, dif) = (self.r + rhs.r, self.r - rhs.r);
+        if sign(sum * sum - d2) < 0 || sign(d2 - dif * dif) < 0 {
+            return vec![];
+        }
+        let p = (d2 + self.r * self.r - rhs.r * rhs.r) / (d2 * f(2.0));
+        let h2 = self.r * self.r - p * p * d2;
+        let mid = self.c + d * p;
+        let per = d.rotate90() * (h2.max(float::ZERO) / d2).sqrt();
+        vec![mid + per, mid - per]
+    }
+
+    ///
+    /// Intersection points with the line through a and b, ordered from a to b, one point if
+    /// the line is tangent
+    ///
+    pub fn intersect_line(&self, a: &P, b: &P) -> Vec<P> {
+        let ab = *b - *a;
+        let p = *a + ab * ((self.c - *a).dot(&ab) / ab.norm2());
+        let s = ab.cross(&(self.c - *a));
+        let h2 = self.r * self.r - s * s / ab.norm2();
+        match sign(h2) {
+            -1 => vec![],
+            0 => vec![p],
+            _ => {
+                let h = ab * (h2.sqrt() / len(&ab));
+                vec![p - h, p + h]
+            }
+        }
+    }
+
+    ///
+    /// Tangent points of the tangent lines through p, one point if p is on the circle and
+    /// none if p is inside
+    ///
+    pub fn tangents_from(&self, p: &P) -> Vec<P> {
+        let d = *p - self.c;
+        let d2 = d.norm2();
+        let h2 = d2 - self.r * self.r;
+        match sign(h2) {
+            -1 => vec![],
+            0 => vec![*p],
+            _ => {
+                let base = self.c + d * (self.r * self.r / d2);
+                let per = d.rotate90() * (self.r * h2.sqrt() / d2);
+                vec![base + per, base - per]
+            }
+        }
+    }
+
+    ///
+    /// Common tangents as pairs of tangent points (on self, on rhs), outer ones first,
+    /// a tangent touching both circles at the same point appears once
+    ///
+    pub fn common_tangents(&self, rhs: &Self) -> Vec<(P, P)> {
+        let d = rhs.c - self.c;
+        let d2 = d.norm2();
+        let mut res = Vec::new();
+        if sign(d2) == 0 {
+            return res;
+        }
+        for r2 in [rhs.r, float::ZERO - rhs.r] {
+            let dr = self.r - r2;
+            let h2 = d2 - dr * dr;
+            if sign(h2) < 0 {
+                continue;
+            }
+            let h = h2.max(float::ZERO).sqrt();
+            for s in [float::ONE, float::ZERO - float::ONE] {
+                let v = (d * dr + d.rotate90() * h * s) / d2;
+                res.push((self.c + v * self.r, rhs.c + v * r2));
+                if sign(h2) == 0 {
+                    break;
+                }
+            }
+        }
+        res
+    }
+
+    ///
+    /// Area of the intersection with a simple polygon given in either order, O(n)
+    ///
+    pub fn intersect_polygon_area(&self, poly: &[P]) -> float {
+        let r2 = self.r * self.r / f(2.0);
+        let tri = |p: P, q: P| -> float {
+            let d = q - p;
+            let a = d.dot(&p) / d.norm2();
+            let b = (p.norm2() - self.r * self.r) / d.norm2();
+            let det = a * a - b;
+            if det <= float::ZERO {
+                return angle(&p, &q) * r2;
+            }
+            let s = (float::ZERO - a - det.sqrt()).max(float::ZERO);
+            let t = (float::ZERO - a + det.sqrt()).min(float::ONE);
+            if t < float::ZERO || float::ONE <= s {
+                return angle(&p, &q) * r2;
+            }
+            let (u, v) = (p + d * s, p + d * t);
+            angle(&p, &u) * r2 + u.cross(&v) / f(2.0) + angle(&v, &q) * r2
+        };
+        let n = poly.len();
+        let mut sum = float::ZERO;
+        for i in 0..n {
+            let (p, q) = (poly[i] - self.c, poly[(i + 1) % n] - self.c);
+            if sign(p.dist2(&q)) > 0 {
+                sum += tri(p, q);
+            }
+        }
+        sum.absolute()
+    }
+}
+
+///
+/// Welzl's algorithm, the smallest circle containing all the points, expected O(n)
+///
+/// # Example
+///
+/// ```ignore
+/// let points: Vec<Point<float>> = ...;
+/// let circle = min_enclosing_circle(&points, &mut Rng::new());
+/// ```
+///
+pub fn min_enclosing_circle(points: &[P], rng: &mut Rng) -> Circle {
+    let mut ps = points.to_vec();
+    shuffle_with(rng, &mut ps);
+    let outside = |circle: &Circle, p: &P| circle.contains(p) == Containment::Outside;
+    let mut res = Circle::new(ps.first().copied().unwrap_or(Point::origin()), float::ZERO);
+    for i in 0..ps.len() {
+        if !outside(&res, &ps[i]) {
+            continue;
+        }
+        res = Circle::new(ps[i], float::ZERO);
+        for j in 0..i {
+            if !outside(&res, &ps[j]) {
+                continue;
+            }
+            let center = (ps[i] + ps[j]) / f(2.0);
+            res = Circle::new(center, len(&(center - ps[i])));
+            for k in 0..j {
+                if outside(&res, &ps[k]) {
+                    res = Circle::circumcircle(&ps[i], &ps[j], &ps[k]).unwrap_or(res);
+                }
+            }
+        }
+    }
+    res
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        circle::*, convex_hull::convex_hull, geometry2d::*, num_float::float, num_number::Number, rand::Rng,
+    };
+
+    fn pt(x: f64, y: f64) -> Point<float> {
+        Point::new(x.into(), y.into())
+    }
+
+    fn close(a: float, b: f64) -> bool {
+        (a.as_f64() - b).abs() < 1e-6
+    }
+
+    fn on_circle(c: &Circle, p: &Point<float>) -> bool {
+        close(c.r, p.dist(&c.c))
+    }
+
+    fn random_circle(rng: &mut Rng) -> Circle {
+        Circle::new(
+            pt(rng.range_i64(-5, 5) as f64, rng.range_i64(-5, 5) as f64),
+            (rng.range_i64(1, 5) as f64).into(),
+        )
+    }
+
+    #[test]
+    fn test_intersections_and_tangents() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..1000 {
+            let (a, b) = (random_circle(&mut rng), random_circle(&mut rng));
+            let d = a.c.dist(&b.c);
+            let (r1, r2) = (a.r.as_f64(), b.r.as_f64());
+            let points = a.intersect_circle(&b);
+            for p in &points {
+                assert!(on_circle(&a, p) && on_circle(&b, p));
+            }
+            let expect_meet = d > 0.0 && d <= r1 + r2 && d >= (r1 - r2).abs();
+            assert_eq!(expect_meet, !points.is_empty());
+
+            let tangents = a.common_tangents(&b);
+            let expect = if d == 0.0 {
+                0
+            } else if d > r1 + r2 {
+                4
+            } else if d == r1 + r2 {
+                3
+            } else if d > (r1 - r2).abs() {
+                2
+            } else if d == (r1 - r2).abs() {
+                1
+            } else {
+                0
+            };
+            assert_eq!(expect, tangents.len());
+            for (p, q) in &tangents {
+                assert!(on_circle(&a, p) && on_circle(&b, q));
+                if p.dist(q) > 1e-9 {
+                    assert!(close(a.r, distance_point_line(&a.c, p, q)));
+                    assert!(close(b.r, distance_point_line(&b.c, p, q)));
+                }
+            }
+
+            let (p, q) = (random_circle(&mut rng).c, random_circle(&mut rng).c);
+            if p != q {
+                let points = a.intersect_line(&p, &q);
+                let dist = distance_point_line(&a.c, &p, &q);
+                assert_eq!(
+                    if dist < r1 - 1e-9 { 2 } else if dist <= r1 + 1e-9 { 1 } else { 0 },
+                    points.len()
+                );
+                for x in &points {
+                    assert!(on_circle(&a, x) && orientation(&p, &q, x) == 0);
+                }
+            }
+            let tangents = a.tangents_from(&p);
+            for t in &tangents {
+                assert!(on_circle(&a, t));
+                assert!(sign((*t - a.c).dot(&(*t - p))) == 0);
+            }
+            let inside = p.dist(&a.c) < r1;
+            assert_eq!(inside, tangents.is_empty());
+        }
+    }
+
+    fn distance_point_line(c: &Point<float>, p: &Point<float>, q: &Point<float>) -> f64 {
+        (*q - *p).cross(&(*c - *p)).as_f64().abs() / p.dist(q)
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        let square = vec![pt(0.0, 0.0), pt(2.0, 0.0), pt(2.0, 2.0), pt(0.0, 2.0)];
+        let pi = std::f64::consts::PI;
+        assert!(close(Circle::new(pt(1.0, 1.0), 10.0.into()).intersect_polygon_area(&square), 4.0));
+        assert!(close(Circle::new(pt(1.0, 1.0), 0.5.into()).intersect_polygon_area(&square), pi / 4.0));
+        assert!(close(Circle::new(pt(0.0, 0.0), 1.0.into()).intersect_polygon_area(&square), pi / 4.0));
+        let rev: Vec<Point<float>> = square.iter().rev().copied().collect();
+        assert!(close(Circle::new(pt(2.0, 1.0), 1.0.into()).intersect_polygon_area(&rev), pi / 2.0));
+
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..20 {
+            let points: Vec<Point<float>> =
+                (0..8).map(|_| pt(rng.range_i64(-4, 4) as f64, rng.range_i64(-4, 4) as f64)).collect();
+            let hull = convex_hull(&points, false);
+            if hull.len() < 3 {
+                continue;
+            }
+            let c = random_circle(&mut rng);
+            // integrate the length of vertical slices
+            let steps = 4000;
+            let (lo, hi) = (-5.0, 5.0);
+            let h = (hi - lo) / steps as f64;
+            let mut expect = 0.0;
+            for i in 0..steps {
+                let x = lo + (i as f64 + 0.5) * h;
+                let (mut y1, mut y2) = (f64::MIN, f64::MAX);
+                for j in 0..hull.len() {
+                    let (a, b) = (hull[j].to_f64(), hull[(j + 1) % hull.len()].to_f64());
+                    if a.x == b.x {
+                        continue;
+                    }
+                    let y = a.y + (b.y - a.y) * (x - a.x) / (b.x - a.x);
+                    if a.x.min(b.x) <= x && x <= a.x.max(b.x) {
+                        if a.x < b.x {
+                            y1 = y1.max(y);
+                        } else {
+                            y2 = y2.min(y);
+                        }
+                    }
+                }
+                let (cx, cy, r) = (c.c.x.as_f64(), c.c.y.as_f64(), c.r.as_f64());
+                let dx = x - cx;
+                if y1 > f64::MIN && y2 < f64::MAX && y1 < y2 && dx.abs() < r {
+                    let w = (r * r - dx * dx).sqrt();
+                    let (l, u) = (y1.max(cy - w), y2.min(cy + w));
+                    if l < u {
+                        expect += (u - l) * h;
+                    }
+                }
+            }
+            assert!((expect - c.intersect_polygon_area(&hull).as_f64()).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_min_enclosing_circle() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let n = rng.range_usize(1, 12);
+            let points: Vec<Point<float>> =
+                (0..n).map(|_| pt(rng.range_i64(-10, 10) as f64, rng.range_i64(-10, 10) as f64)).collect();
+            let res = min_enclosing_circle(&points, &mut rng);
+            let covers = |c: &Circle| points.iter().all(|p| c.contains(p) != Containment::Outside);
+            assert!(covers(&res));
+            let mut best = if n == 1 { 0.0 } else { f64::MAX };
+            for i in 0..n {
+                for j in 0..i {
+                    let center = (points[i] + points[j]) / float::from(2.0);
+                    let c = Circle::new(center, float::from(center.dist(&points[i])));
+                    if covers(&c) {
+                        best = best.min(c.r.as_f64());
+                    }
+                    for k in 0..j {
+                        if let Some(c) = Circle::circumcircle(&points[i], &points[j], &points[k]) {
+                            if covers(&c) {
+                                best = best.min(c.r.as_f64());
+                            }
+                        }
+                    }
+                }
+            }
+            assert!(close(res.r, best));
+        }
+    }
+}
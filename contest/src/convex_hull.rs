@@ -0,0 +1,223 @@
+use crate::{
+    geometry2d::{orientation, sign, Containment, Point},
+    num_number::Number,
+};
+
+///
+/// Andrew's monotone chain, return the hull counterclockwise starting from the smallest
+/// point by (x, y), O(n log n)
+///
+/// Points on the hull edges are kept if keep_collinear, duplicated points are always removed
+///
+/// # Example
+///
+/// ```ignore
+/// let points = vec![Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(1, 1)];
+/// assert_eq!(3, convex_hull(&points, false).len());
+/// assert_eq!(4, convex_hull(&points, true).len());
+/// ```
+///
+pub fn convex_hull<T: Number>(points: &[Point<T>], keep_collinear: bool) -> Vec<Point<T>> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    points.dedup();
+    let n = points.len();
+    if n <= 2 {
+        return points;
+    }
+    if (2..n).all(|i| orientation(&points[0], &points[1], &points[i]) == 0) {
+        return if keep_collinear {
+            points
+        } else {
+            vec![points[0], points[n - 1]]
+        };
+    }
+    let pop = |o: i8| if keep_collinear { o < 0 } else { o <= 0 };
+    let mut hull: Vec<Point<T>> = Vec::with_capacity(2 * n);
+    for p in &points {
+        while hull.len() >= 2 && pop(orientation(&hull[hull.len() - 2], &hull[hull.len() - 1], p)) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    let lower = hull.len() + 1;
+    for i in (0..n - 1).rev() {
+        while hull.len() >= lower
+            && pop(orientation(&hull[hull.len() - 2], &hull[hull.len() - 1], &points[i]))
+        {
+            hull.pop();
+        }
+        hull.push(points[i]);
+    }
+    hull.pop();
+    hull
+}
+
+///
+/// (squared distance, i, j) of the farthest pair of vertices of a convex polygon given
+/// counterclockwise, by rotating calipers, O(n)
+///
+pub fn diameter<T: Number>(hull: &[Point<T>]) -> (T::HighPrecisionType, usize, usize) {
+    let n = hull.len();
+    let mut best = (<T::HighPrecisionType as Number>::ZERO, 0, 0);
+    if n <= 1 {
+        return best;
+    }
+    let mut j = 1;
+    for i in 0..n {
+        let edge = hull[(i + 1) % n] - hull[i];
+        while sign(edge.cross(&(hull[(j + 1) % n] - hull[j]))) > 0 {
+            j = (j + 1) % n;
+        }
+        for k in [i, (i + 1) % n] {
+            let d = hull[k].dist2(&hull[j]);
+            if d > best.0 {
+                best = (d, k, j);
+            }
+        }
+    }
+    best
+}
+
+///
+/// Minimum distance between two parallel lines enclosing a convex polygon given
+/// counterclockwise, by rotating calipers, O(n)
+///
+pub fn width<T: Number>(hull: &[Point<T>]) -> f64 {
+    let n = hull.len();
+    if n <= 2 {
+        return 0.0;
+    }
+    let mut best = f64::MAX;
+    let mut j = 1;
+    for i in 0..n {
+        let edge = hull[(i + 1) % n] - hull[i];
+        while sign(edge.cross(&(hull[(j + 1) % n] - hull[j]))) > 0 {
+            j = (j + 1) % n;
+        }
+        best = best.min(edge.cross(&(hull[j] - hull[i])).as_f64() / edge.norm());
+    }
+    best
+}
+
+///
+/// Minkowski sum of two convex polygons given counterclockwise, O(n + m)
+///
+pub fn minkowski_sum<T: Number>(p: &[Point<T>], q: &[Point<T>]) -> Vec<Point<T>> {
+    let lowest = |poly: &[Point<T>]| -> Vec<Point<T>> {
+        let start = (0..poly.len())
+            .min_by(|&a, &b| {
+                (poly[a].y, poly[a].x)
+                    .partial_cmp(&(poly[b].y, poly[b].x))
+                    .unwrap()
+            })
+            .unwrap();
+        let mut res: Vec<Point<T>> = poly[start..].iter().chain(&poly[..start]).copied().collect();
+        res.push(res[0]);
+        res.push(res[1 % poly.len()]);
+        res
+    };
+    let (n, m) = (p.len(), q.len());
+    let (p, q) = (lowest(p), lowest(q));
+    let mut res = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        res.push(p[i] + q[j]);
+        let cross = sign((p[i + 1] - p[i]).cross(&(q[j + 1] - q[j])));
+        if cross >= 0 && i < n {
+            i += 1;
+        }
+        if cross <= 0 && j < m {
+            j += 1;
+        }
+    }
+    res
+}
+
+///
+/// Locate p against a convex polygon given counterclockwise without collinear vertices,
+/// O(log n)
+///
+pub fn point_in_convex<T: Number>(hull: &[Point<T>], p: &Point<T>) -> Containment {
+    let n = hull.len();
+    if n == 0 {
+        return Containment::Outside;
+    }
+    if n <= 2 {
+        return if crate::geometry2d::on_segment(p, &hull[0], &hull[n - 1]) {
+            Containment::OnBoundary
+        } else {
+            Containment::Outside
+        };
+    }
+    let o = &hull[0];
+    if orientation(o, &hull[1], p) < 0 || orientation(o, &hull[n - 1], p) > 0 {
+        return Containment::Outside;
+    }
+    // the last i in [1, n - 2] with p not on the right of o -> hull[i]
+    let (mut l, mut r) = (1, n - 2);
+    while l < r {
+        let m = (l + r).div_ceil(2);
+        if orientation(o, &hull[m], p) >= 0 {
+            l = m;
+        } else {
+            r = m - 1;
+        }
+    }
+    match orientation(&hull[l], &hull[l + 1], p) {
+        x if x < 0 => Containment::Outside,
+        0 => Containment::OnBoundary,
+        _ => {
+            let on_first = l == 1 && orientation(o, &hull[1], p) == 0;
+            let on_last = l + 1 == n - 1 && orientation(o, &hull[n - 1], p) == 0;
+            if on_first || on_last {
+                Containment::OnBoundary
+            } else {
+                Containment::Inside
+            }
+        }
+    }
+}
+
+///
+/// Binary search the vertex maximizing a function that goes up and then down along the
+/// polygon, `cmp(i, j)` is the sign of f(i) - f(j)
+///
+fn extreme_vertex(n: usize, cmp: impl Fn(usize, usize) -> i8) -> usize {
+    let cmp = |i: usize, j: usize| cmp(i % n, j % n);
+    let extreme = |i: usize| cmp(i + 1, i) <= 0 && cmp(i, i + n - 1) > 0;
+    if extreme(0) {
+        return 0;
+    }
+    let (mut lo, mut hi) = (0, n);
+    while lo + 1 < hi {
+        let m = (lo + hi) / 2;
+        if extreme(m) {
+            return m;
+        }
+        let (ls, ms) = (cmp(lo + 1, lo), cmp(m + 1, m));
+        if ls > ms || (ls == ms && ls == cmp(lo, m)) {
+            hi = m;
+        } else {
+            lo = m;
+        }
+    }
+    lo
+}
+
+///
+/// Tangent vertices (left, right) of a convex polygon given counterclockwise without
+/// collinear vertices, seen from p strictly outside, O(log n)
+///
+/// The whole polygon lies on the right of p -> hull[left] and on the left of p -> hull[right]
+///
+pub fn tangents<T: Number>(hull: &[Point<T>], p: &Point<T>) -> (usize, usize) {
+    let n = hull.len();
+    if n == 1 {
+        return (0, 0);
+    }
+    let ccw = |i: usize, j: usize| orientation(p, &hull[j], &hull[i]);
+    let left = extreme_vertex(n, ccw);
+    let right = extreme_vertex(n, |i, j| ccw(j, i));
+    (left, right)
+}
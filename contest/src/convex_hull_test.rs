@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::{convex_hull::*, geometry2d::*, rand::Rng};
+
+    fn random_points(rng: &mut Rng, n: usize, range: i64) -> Vec<Point<i64>> {
+        (0..n)
+            .map(|_| Point::new(rng.range_i64(-range, range), rng.range_i64(-range, range)))
+            .collect()
+    }
+
+    fn is_hull(hull: &[Point<i64>], points: &[Point<i64>], strict: bool) {
+        let n = hull.len();
+        for i in 0..n {
+            let (a, b, c) = (&hull[i], &hull[(i + 1) % n], &hull[(i + 2) % n]);
+            let o = orientation(a, b, c);
+            assert!(if strict { o > 0 } else { o >= 0 });
+            for p in points {
+                assert!(orientation(a, b, p) >= 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(3, 30);
+            let points = random_points(&mut rng, n, 5);
+            let hull = convex_hull(&points, false);
+            if hull.len() <= 2 {
+                continue;
+            }
+            is_hull(&hull, &points, true);
+            let full = convex_hull(&points, true);
+            is_hull(&full, &points, false);
+            let on_boundary: Vec<&Point<i64>> = points
+                .iter()
+                .filter(|p| point_in_polygon(p, &hull) == Containment::OnBoundary)
+                .collect();
+            let mut expect: Vec<Point<i64>> = on_boundary.into_iter().copied().collect();
+            expect.sort();
+            expect.dedup();
+            let mut actual = full.clone();
+            actual.sort();
+            assert_eq!(expect, actual);
+
+            let (d, i, j) = diameter(&hull);
+            let brute = points.iter().flat_map(|a| points.iter().map(move |b| a.dist2(b))).max().unwrap();
+            assert_eq!(brute, d);
+            assert_eq!(d, hull[i].dist2(&hull[j]));
+
+            let brute_width = (0..hull.len())
+                .map(|i| {
+                    let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+                    hull.iter().map(|p| (b - a).cross(&(*p - a)) as f64 / a.dist(&b)).fold(0.0, f64::max)
+                })
+                .fold(f64::MAX, f64::min);
+            assert!((brute_width - width(&hull)).abs() < 1e-9);
+
+            for _ in 0..20 {
+                let p = random_points(&mut rng, 1, 7)[0];
+                assert_eq!(point_in_polygon(&p, &hull), point_in_convex(&hull, &p));
+                if point_in_polygon(&p, &hull) == Containment::Outside {
+                    let (l, r) = tangents(&hull, &p);
+                    for q in &hull {
+                        assert!(orientation(&p, &hull[l], q) <= 0);
+                        assert!(orientation(&p, &hull[r], q) >= 0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_minkowski_sum() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let (n, m) = (rng.range_usize(3, 12), rng.range_usize(3, 12));
+            let p = convex_hull(&random_points(&mut rng, n, 5), false);
+            let q = convex_hull(&random_points(&mut rng, m, 5), false);
+            if p.len() <= 2 || q.len() <= 2 {
+                continue;
+            }
+            let sums: Vec<Point<i64>> = p.iter().flat_map(|a| q.iter().map(move |b| *a + *b)).collect();
+            let mut expect = convex_hull(&sums, false);
+            let mut actual = convex_hull(&minkowski_sum(&p, &q), false);
+            expect.sort();
+            actual.sort();
+            assert_eq!(expect, actual);
+            is_hull(&minkowski_sum(&p, &q), &sums, false);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+///
+/// root of the palindromes of odd length, with length -1
+///
+pub const ODD_ROOT: usize = 0;
+///
+/// root of the palindromes of even length, the empty string
+///
+pub const EVEN_ROOT: usize = 1;
+
+pub struct Node<const C: usize> {
+    pub len: isize,
+    ///
+    /// longest proper palindromic suffix
+    ///
+    pub link: usize,
+    ///
+    /// len - len of link, 0 for the roots
+    ///
+    pub diff: isize,
+    ///
+    /// first node on the suffix link chain whose diff differs, suffix palindromes form
+    /// O(log n) arithmetic series separated by series links
+    ///
+    pub series_link: usize,
+    ///
+    /// children by appending the same character on both sides, ODD_ROOT for none
+    ///
+    pub next: [usize; C],
+    count: u64,
+}
+
+///
+/// Palindromic tree over characters in [0, C), O(n C) memory and O(n) amortized time
+///
+/// # Example
+///
+/// ```ignore
+/// let mut tree = Eertree::<26>::new(0);
+/// for c in b"abaab" {
+///     tree.append((c - b'a') as usize);
+/// }
+/// assert_eq!(5, tree.distinct());
+/// ```
+///
+pub struct Eertree<const C: usize> {
+    nodes: Vec<Node<C>>,
+    s: Vec<usize>,
+    ends: Vec<usize>,
+}
+
+impl<const C: usize> Eertree<C> {
+    pub fn new(estimate_len: usize) -> Self {
+        let mut ans = Self {
+            nodes: Vec::with_capacity(estimate_len + 2),
+            s: Vec::with_capacity(estimate_len),
+            ends: Vec::with_capacity(estimate_len),
+        };
+        ans.new_node(-1, ODD_ROOT);
+        ans.new_node(0, ODD_ROOT);
+        ans
+    }
+
+    fn new_node(&mut self, len: isize, link: usize) -> usize {
+        let diff = if len <= 0 { 0 } else { len - self.nodes[link].len };
+        let series_link = match self.nodes.get(link) {
+            Some(x) if len > 0 && x.diff == diff => x.series_link,
+            _ => link,
+        };
+        self.nodes.push(Node {
+            len,
+            link,
+            diff,
+            series_link,
+            next: [ODD_ROOT; C],
+            count: 0,
+        });
+        self.nodes.len() - 1
+    }
+
+    ///
+    /// Walk the suffix link chain from v to the first palindrome that extends to s[i]
+    ///
+    fn extendable(&self, mut v: usize, i: usize) -> usize {
+        loop {
+            let j = i as isize - self.nodes[v].len - 1;
+            if j >= 0 && self.s[j as usize] == self.s[i] {
+                return v;
+            }
+            v = self.nodes[v].link;
+        }
+    }
+
+    ///
+    /// Append c, return the node of the longest palindromic suffix of the new string
+    ///
+    pub fn append(&mut self, c: usize) -> usize {
+        let i = self.s.len();
+        self.s.push(c);
+        let last = self.ends.last().copied().unwrap_or(EVEN_ROOT);
+        let cur = self.extendable(last, i);
+        let mut v = self.nodes[cur].next[c];
+        if v == ODD_ROOT {
+            let len = self.nodes[cur].len + 2;
+            let link = if len == 1 {
+                EVEN_ROOT
+            } else {
+                let u = self.extendable(self.nodes[cur].link, i);
+                self.nodes[u].next[c]
+            };
+            v = self.new_node(len, link);
+            self.nodes[cur].next[c] = v;
+        }
+        self.nodes[v].count += 1;
+        self.ends.push(v);
+        v
+    }
+
+    pub fn node(&self, v: usize) -> &Node<C> {
+        &self.nodes[v]
+    }
+
+    ///
+    /// Number of nodes including the two roots
+    ///
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    ///
+    /// Number of distinct non-empty palindromic substrings
+    ///
+    pub fn distinct(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    ///
+    /// Node of the longest palindromic suffix of s[..=i]
+    ///
+    pub fn suffix_node(&self, i: usize) -> usize {
+        self.ends[i]
+    }
+
+    ///
+    /// Length of the longest palindromic suffix of s[..=i]
+    ///
+    pub fn longest_suffix(&self, i: usize) -> usize {
+        self.nodes[self.ends[i]].len as usize
+    }
+
+    ///
+    /// Number of occurrences of the palindrome of every node, 0 for the roots
+    ///
+    pub fn occurrences(&self) -> Vec<u64> {
+        let mut res: Vec<u64> = self.nodes.iter().map(|x| x.count).collect();
+        for v in (EVEN_ROOT + 1..self.nodes.len()).rev() {
+            let link = self.nodes[v].link;
+            res[link] += res[v];
+        }
+        res[ODD_ROOT] = 0;
+        res[EVEN_ROOT] = 0;
+        res
+    }
+
+    ///
+    /// dp[i] is the minimum number of palindromes s[..i] splits into, O(n log n) by
+    /// series links
+    ///
+    pub fn min_palindrome_factorization(&self) -> Vec<usize> {
+        let n = self.s.len();
+        let mut dp = vec![usize::MAX; n + 1];
+        let mut series = vec![usize::MAX; self.nodes.len()];
+        dp[0] = 0;
+        for i in 1..=n {
+            let mut v = self.ends[i - 1];
+            while self.nodes[v].len > 0 {
+                let node = &self.nodes[v];
+                let start = self.nodes[node.series_link].len + node.diff;
+                series[v] = dp[i - start as usize];
+                if node.diff == self.nodes[node.link].diff {
+                    series[v] = series[v].min(series[node.link]);
+                }
+                dp[i] = dp[i].min(series[v] + 1);
+                v = node.series_link;
+            }
+        }
+        dp
+    }
+}
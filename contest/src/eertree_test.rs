@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{eertree::Eertree, rand::Rng};
+
+    fn is_palindrome(s: &[usize]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn test_eertree() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 30);
+            let s: Vec<usize> = (0..n).map(|_| rng.limit_usize(2)).collect();
+            let mut tree = Eertree::<2>::new(n);
+            for &c in &s {
+                tree.append(c);
+            }
+            let mut expect = BTreeMap::new();
+            for i in 0..n {
+                for j in i + 1..=n {
+                    if is_palindrome(&s[i..j]) {
+                        *expect.entry(s[i..j].to_vec()).or_insert(0u64) += 1;
+                    }
+                }
+            }
+            assert_eq!(expect.len(), tree.distinct());
+            let occ = tree.occurrences();
+            for i in 0..n {
+                let len = (1..=i + 1).filter(|&k| is_palindrome(&s[i + 1 - k..=i])).max().unwrap();
+                assert_eq!(len, tree.longest_suffix(i));
+                let v = tree.suffix_node(i);
+                assert_eq!(expect[&s[i + 1 - len..=i]], occ[v]);
+            }
+            let mut dp = vec![usize::MAX; n + 1];
+            dp[0] = 0;
+            for i in 1..=n {
+                for j in 0..i {
+                    if is_palindrome(&s[j..i]) {
+                        dp[i] = dp[i].min(dp[j] + 1);
+                    }
+                }
+            }
+            assert_eq!(dp, tree.min_palindrome_factorization());
+        }
+    }
+}
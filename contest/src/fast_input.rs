@@ -1,4 +1,113 @@
-use std::io::{self, BufRead, BufReader, Cursor};
+use std::{
+    fmt::Display,
+    io::{self, BufRead, BufReader, Cursor},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    Eof,
+    ///
+    /// token that can't be parsed into the required type
+    ///
+    Format(String),
+    ///
+    /// error of the underlying reader, such as a timed out pipe
+    ///
+    Io(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Eof => write!(f, "Unexpected end of input"),
+            InputError::Format(token) => write!(f, "Wrong format input `{}`", token),
+            InputError::Io(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+///
+/// Value parsed from a token without going through `str`, None if the token is malformed
+/// or out of range
+///
+pub trait FromBytes: Sized {
+    fn from_bytes(s: &[u8]) -> Option<Self>;
+}
+
+macro_rules! FromBytesUnsignedImpl {
+    ($($t: ty),*) => {
+        $(
+            impl FromBytes for $t {
+                #[inline]
+                fn from_bytes(s: &[u8]) -> Option<Self> {
+                    let s = s.strip_prefix(b"+").unwrap_or(s);
+                    if s.is_empty() {
+                        return None;
+                    }
+                    let mut res: $t = 0;
+                    for &c in s {
+                        if !c.is_ascii_digit() {
+                            return None;
+                        }
+                        res = res.checked_mul(10)?.checked_add((c - b'0') as $t)?;
+                    }
+                    Some(res)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! FromBytesSignedImpl {
+    ($($t: ty),*) => {
+        $(
+            impl FromBytes for $t {
+                #[inline]
+                fn from_bytes(s: &[u8]) -> Option<Self> {
+                    let (neg, s) = match s.first() {
+                        Some(b'-') => (true, &s[1..]),
+                        Some(b'+') => (false, &s[1..]),
+                        _ => (false, s),
+                    };
+                    if s.is_empty() {
+                        return None;
+                    }
+                    //accumulate negatively so that MIN can be parsed
+                    let mut res: $t = 0;
+                    for &c in s {
+                        if !c.is_ascii_digit() {
+                            return None;
+                        }
+                        res = res.checked_mul(10)?.checked_sub((c - b'0') as $t)?;
+                    }
+                    if neg {
+                        Some(res)
+                    } else {
+                        res.checked_neg()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! FromBytesStrImpl {
+    ($($t: ty),*) => {
+        $(
+            impl FromBytes for $t {
+                #[inline]
+                fn from_bytes(s: &[u8]) -> Option<Self> {
+                    std::str::from_utf8(s).ok()?.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+FromBytesUnsignedImpl!(u8, u16, u32, u64, u128, usize);
+FromBytesSignedImpl!(i8, i16, i32, i64, i128, isize);
+FromBytesStrImpl!(f32, f64, char, String);
+
 ///
 /// Fast input for competitive programming
 ///
@@ -13,6 +122,8 @@ use std::io::{self, BufRead, BufReader, Cursor};
 /// let mut fi = FastInput::new(stdin.lock());
 /// ```
 ///
+/// `FastInput::slurp` reads the whole input into one buffer at once, which is faster
+/// for large input but can't be used for interactive problems.
 ///
 pub struct FastInput<R: std::io::BufRead> {
     inner: R,
@@ -39,6 +150,19 @@ impl<R: std::io::BufRead> FastInput<R> {
         }
     }
 
+    ///
+    /// Read everything of inner into the buffer, the buffer is then treated as one long line
+    ///
+    pub fn slurp(mut inner: R) -> Self {
+        let mut line = Vec::new();
+        inner.read_to_end(&mut line).unwrap();
+        FastInput {
+            inner,
+            line,
+            offset: 0,
+        }
+    }
+
     ///
     /// Read next token of current processed line
     ///
@@ -62,14 +186,26 @@ impl<R: std::io::BufRead> FastInput<R> {
         }
     }
 
+    ///
+    /// # Panic
+    ///
+    /// Panic if inner fails to read
+    ///
     pub fn eof(&mut self) -> bool {
+        match self.try_eof() {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_eof(&mut self) -> Result<bool, InputError> {
         loop {
             self.skip_blank();
             if self.offset < self.line.len() {
-                return false;
+                return Ok(false);
             }
-            if !self.refill() {
-                return true;
+            if !self.refill()? {
+                return Ok(true);
             }
         }
     }
@@ -77,18 +213,27 @@ impl<R: std::io::BufRead> FastInput<R> {
     ///
     /// Read a specified type element from input
     ///
-    /// # Unsafe
+    /// # Panic
     ///
-    /// This method might enter infinite loop if no more input available
+    /// Panic if no more input available
     ///
     pub fn read<T: std::str::FromStr>(&mut self) -> T {
+        match self.try_read() {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_read<T: std::str::FromStr>(&mut self) -> Result<T, InputError> {
         loop {
             match self.next() {
                 Some(token) => {
-                    return token.parse().ok().expect("Wrong format input");
+                    return token.parse().map_err(|_| InputError::Format(token.to_string()));
                 }
                 None => {
-                    self.refill();
+                    if !self.refill()? {
+                        return Err(InputError::Eof);
+                    }
                 }
             }
         }
@@ -97,11 +242,141 @@ impl<R: std::io::BufRead> FastInput<R> {
     ///
     /// return false if the stream reached eof 
     /// 
-    fn refill(&mut self) -> bool {
+    fn refill(&mut self) -> Result<bool, InputError> {
         self.line.clear();
-        let num = self.inner.read_until(b'\n', &mut self.line).unwrap();
         self.offset = 0;
-        return num > 0;
+        let num = self
+            .inner
+            .read_until(b'\n', &mut self.line)
+            .map_err(|e| InputError::Io(e.to_string()))?;
+        Ok(num > 0)
+    }
+
+    ///
+    /// Next token as bytes, borrowed from the internal buffer
+    ///
+    pub fn try_token(&mut self) -> Result<&[u8], InputError> {
+        loop {
+            self.skip_blank();
+            if self.offset < self.line.len() {
+                break;
+            }
+            if !self.refill()? {
+                return Err(InputError::Eof);
+            }
+        }
+        let begin = self.offset;
+        while self.offset < self.line.len() && self.line[self.offset] > 32 {
+            self.offset += 1;
+        }
+        Ok(&self.line[begin..self.offset])
+    }
+
+    ///
+    /// # Panic
+    ///
+    /// Panic if no more input available
+    ///
+    pub fn token(&mut self) -> &[u8] {
+        match self.try_token() {
+            Ok(token) => token,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    ///
+    /// Parse next token directly from bytes
+    ///
+    pub fn try_read_bytes<T: FromBytes>(&mut self) -> Result<T, InputError> {
+        let token = self.try_token()?;
+        T::from_bytes(token).ok_or_else(|| InputError::Format(String::from_utf8_lossy(token).to_string()))
+    }
+
+    ///
+    /// # Panic
+    ///
+    /// Panic if no more input available or the token is malformed
+    ///
+    pub fn rb<T: FromBytes>(&mut self) -> T {
+        match self.try_read_bytes() {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_read_vec<T: FromBytes>(&mut self, n: usize) -> Result<Vec<T>, InputError> {
+        (0..n).map(|_| self.try_read_bytes()).collect()
+    }
+
+    ///
+    /// # Panic
+    ///
+    /// Panic if less than n values available
+    ///
+    pub fn read_vec<T: FromBytes>(&mut self, n: usize) -> Vec<T> {
+        match self.try_read_vec(n) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    ///
+    /// Rest of current line without the line break, or the next line if nothing but blanks
+    /// remains on current line
+    ///
+    pub fn try_line(&mut self) -> Result<&[u8], InputError> {
+        let at_line_start = self.offset == 0 || self.line[self.offset - 1] == b'\n';
+        let rest = &self.line[self.offset..];
+        let end = rest.iter().position(|&c| c == b'\n').map(|x| x + 1).unwrap_or(rest.len());
+        if !at_line_start && rest[..end].iter().all(|&c| c <= 32) {
+            self.offset += end;
+        }
+        if self.offset == self.line.len() && !self.refill()? {
+            return Err(InputError::Eof);
+        }
+        let begin = self.offset;
+        while self.offset < self.line.len() && self.line[self.offset] != b'\n' {
+            self.offset += 1;
+        }
+        let mut end = self.offset;
+        if self.offset < self.line.len() {
+            self.offset += 1;
+        }
+        if end > begin && self.line[end - 1] == b'\r' {
+            end -= 1;
+        }
+        Ok(&self.line[begin..end])
+    }
+
+    ///
+    /// # Panic
+    ///
+    /// Panic if no more input available
+    ///
+    pub fn line(&mut self) -> &[u8] {
+        match self.try_line() {
+            Ok(line) => line,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    ///
+    /// n rows of characters, each row is a token
+    ///
+    pub fn try_grid(&mut self, n: usize) -> Result<Vec<Vec<u8>>, InputError> {
+        (0..n).map(|_| self.try_token().map(|x| x.to_vec())).collect()
+    }
+
+    ///
+    /// # Panic
+    ///
+    /// Panic if less than n rows available
+    ///
+    pub fn grid(&mut self, n: usize) -> Vec<Vec<u8>> {
+        match self.try_grid(n) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
     }
 
     pub fn r<T: std::str::FromStr>(&mut self) -> T {
@@ -19,4 +19,80 @@ mod tests {
         assert_eq!(1.5, reader.read());
         assert!(reader.eof());
     }
+
+    fn inputs(s: &str) -> Vec<FastInput<BufReader<Cursor<String>>>> {
+        vec![
+            FastInput::new(BufReader::new(Cursor::new(s.to_string()))),
+            FastInput::slurp(BufReader::new(Cursor::new(s.to_string()))),
+        ]
+    }
+
+    #[test]
+    fn test_read_bytes() {
+        for mut fi in inputs("3\r\n-9223372036854775808 +7 18446744073709551615\n#.#\n.#.\n x") {
+            assert_eq!(3usize, fi.rb());
+            assert_eq!(i64::MIN, fi.rb());
+            assert_eq!(7i32, fi.rb());
+            assert_eq!(u64::MAX, fi.rb());
+            assert_eq!(vec![b"#.#".to_vec(), b".#.".to_vec()], fi.grid(2));
+            assert_eq!(Err(InputError::Format("x".to_string())), fi.try_read_bytes::<i32>());
+            assert_eq!(Err(InputError::Eof), fi.try_read_bytes::<i32>());
+            assert_eq!(Err(InputError::Eof), fi.try_token());
+            assert_eq!(Err(InputError::Eof), fi.try_read::<String>());
+            assert!(fi.eof());
+        }
+    }
+
+    #[test]
+    fn test_read_vec_and_line() {
+        for mut fi in inputs("2\n1 2\nhello world\r\n\nlast") {
+            let n: usize = fi.rb();
+            assert_eq!(vec![1i64, 2], fi.read_vec(n));
+            assert_eq!(b"hello world", fi.line());
+            assert_eq!(b"", fi.line());
+            assert_eq!(b"last", fi.token());
+            assert_eq!(Err(InputError::Eof), fi.try_line());
+            assert_eq!(Err(InputError::Eof), fi.try_read_vec::<i32>(1));
+        }
+        for mut fi in inputs("1 rest of line\n") {
+            assert_eq!(1u8, fi.rb());
+            assert_eq!(b" rest of line", fi.line());
+        }
+    }
+
+    #[test]
+    fn test_read_bytes_out_of_range() {
+        for mut fi in inputs("18446744073709551616 9223372036854775808 -9223372036854775809 256 -129 -0") {
+            let x = InputError::Format;
+            assert_eq!(Err(x("18446744073709551616".to_string())), fi.try_read_bytes::<u64>());
+            assert_eq!(Err(x("9223372036854775808".to_string())), fi.try_read_bytes::<i64>());
+            assert_eq!(Err(x("-9223372036854775809".to_string())), fi.try_read_bytes::<i64>());
+            assert_eq!(Err(x("256".to_string())), fi.try_read_bytes::<u8>());
+            assert_eq!(Err(x("-129".to_string())), fi.try_read_bytes::<i8>());
+            assert_eq!(Ok(0i8), fi.try_read_bytes());
+        }
+        for mut fi in inputs("18446744073709551615 9223372036854775807 -9223372036854775808 255 -128") {
+            assert_eq!(u64::MAX, fi.rb());
+            assert_eq!(i64::MAX, fi.rb());
+            assert_eq!(i64::MIN, fi.rb());
+            assert_eq!(u8::MAX, fi.rb());
+            assert_eq!(i8::MIN, fi.rb());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected end of input")]
+    fn test_read_after_eof() {
+        let mut fi = new_fastinput_from_string("1".to_string());
+        let _: i32 = fi.read();
+        let _: i32 = fi.read();
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected end of input")]
+    fn test_line_after_eof() {
+        let mut fi = FastInput::slurp(BufReader::new(Cursor::new("a\n")));
+        fi.line();
+        fi.line();
+    }
 }
@@ -0,0 +1,323 @@
+use std::io::{self, Write};
+
+use crate::{
+    dynamic_modint::{DynamicModInt, DynamicModulusFactory},
+    modint::ModInt,
+    num_integer::Integer,
+    static_modint::{StaticModInt, StaticModulusFactory},
+};
+
+const BUF_SIZE: usize = 1 << 16;
+
+const DIGITS: &[u8; 200] = b"\
+0001020304050607080910111213141516171819\
+2021222324252627282930313233343536373839\
+4041424344454647484950515253545556575859\
+6061626364656667686970717273747576777879\
+8081828384858687888990919293949596979899";
+
+///
+/// Value that can be appended to output without going through `std::fmt`
+///
+pub trait FastWrite {
+    fn fast_write(&self, buf: &mut Vec<u8>);
+}
+
+fn write_u64(buf: &mut Vec<u8>, mut x: u64) {
+    let mut tmp = [0u8; 20];
+    let mut i = tmp.len();
+    while x >= 100 {
+        let d = (x % 100) as usize * 2;
+        x /= 100;
+        i -= 2;
+        tmp[i..i + 2].copy_from_slice(&DIGITS[d..d + 2]);
+    }
+    if x >= 10 {
+        let d = x as usize * 2;
+        i -= 2;
+        tmp[i..i + 2].copy_from_slice(&DIGITS[d..d + 2]);
+    } else {
+        i -= 1;
+        tmp[i] = b'0' + x as u8;
+    }
+    buf.extend_from_slice(&tmp[i..]);
+}
+
+fn write_u128(buf: &mut Vec<u8>, x: u128) {
+    const E19: u128 = 10_000_000_000_000_000_000;
+    if x < E19 {
+        write_u64(buf, x as u64);
+        return;
+    }
+    write_u128(buf, x / E19);
+    let low = (x % E19) as u64;
+    let start = buf.len();
+    write_u64(buf, low);
+    let len = buf.len() - start;
+    //pad the lower part to 19 digits
+    buf.splice(start..start, std::iter::repeat_n(b'0', 19 - len));
+}
+
+macro_rules! FastWriteUnsignedImpl {
+    ($($t: ty),*) => {
+        $(
+            impl FastWrite for $t {
+                #[inline]
+                fn fast_write(&self, buf: &mut Vec<u8>) {
+                    write_u64(buf, *self as u64);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! FastWriteSignedImpl {
+    ($($t: ty),*) => {
+        $(
+            impl FastWrite for $t {
+                #[inline]
+                fn fast_write(&self, buf: &mut Vec<u8>) {
+                    if *self < 0 {
+                        buf.push(b'-');
+                    }
+                    write_u64(buf, self.unsigned_abs() as u64);
+                }
+            }
+        )*
+    };
+}
+
+FastWriteUnsignedImpl!(u8, u16, u32, u64, usize);
+FastWriteSignedImpl!(i8, i16, i32, i64, isize);
+
+impl FastWrite for u128 {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        write_u128(buf, *self);
+    }
+}
+
+impl FastWrite for i128 {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        if *self < 0 {
+            buf.push(b'-');
+        }
+        write_u128(buf, self.unsigned_abs());
+    }
+}
+
+impl FastWrite for f64 {
+    ///
+    /// Same as Display, use `FastOutput::float` for fixed precision
+    ///
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.to_string().as_bytes());
+    }
+}
+
+impl FastWrite for char {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        let mut tmp = [0u8; 4];
+        buf.extend_from_slice(self.encode_utf8(&mut tmp).as_bytes());
+    }
+}
+
+impl FastWrite for str {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl FastWrite for String {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl<T: FastWrite + ?Sized> FastWrite for &T {
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        (**self).fast_write(buf);
+    }
+}
+
+impl<T, F> FastWrite for StaticModInt<T, F>
+where
+    T: 'static + Integer + FastWrite,
+    F: StaticModulusFactory<T>,
+{
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        self.value().fast_write(buf);
+    }
+}
+
+impl<T, F> FastWrite for DynamicModInt<T, F>
+where
+    T: 'static + Integer + FastWrite,
+    F: DynamicModulusFactory<T>,
+{
+    fn fast_write(&self, buf: &mut Vec<u8>) {
+        self.value().fast_write(buf);
+    }
+}
+
+///
+/// Buffered output for competitive programming
+///
+/// It implements `Write` as well so `write!` still works, and it is flushed when dropped.
+///
+/// # Example
+///
+/// ```not_run
+/// let stdout = io::stdout();
+/// let mut fo = FastOutput::new(stdout.lock());
+/// fo.println(1).print_slice(&[1, 2, 3], b' ').newline().yes_no(true);
+/// ```
+///
+pub struct FastOutput<W: Write> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> FastOutput<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buf: Vec::with_capacity(BUF_SIZE),
+        }
+    }
+
+    #[inline]
+    fn check_buffer(&mut self) {
+        if self.buf.len() >= BUF_SIZE {
+            self.inner.write_all(&self.buf).unwrap();
+            self.buf.clear();
+        }
+    }
+
+    #[inline]
+    pub fn print<T: FastWrite>(&mut self, x: T) -> &mut Self {
+        x.fast_write(&mut self.buf);
+        self.check_buffer();
+        self
+    }
+
+    #[inline]
+    pub fn println<T: FastWrite>(&mut self, x: T) -> &mut Self {
+        x.fast_write(&mut self.buf);
+        self.buf.push(b'\n');
+        self.check_buffer();
+        self
+    }
+
+    #[inline]
+    pub fn space(&mut self) -> &mut Self {
+        self.buf.push(b' ');
+        self
+    }
+
+    #[inline]
+    pub fn newline(&mut self) -> &mut Self {
+        self.buf.push(b'\n');
+        self.check_buffer();
+        self
+    }
+
+    ///
+    /// Elements separated by sep, without trailing separator
+    ///
+    pub fn print_slice<T: FastWrite>(&mut self, data: &[T], sep: u8) -> &mut Self {
+        for (i, x) in data.iter().enumerate() {
+            if i > 0 {
+                self.buf.push(sep);
+            }
+            x.fast_write(&mut self.buf);
+            self.check_buffer();
+        }
+        self
+    }
+
+    ///
+    /// Elements separated by space in one line
+    ///
+    pub fn println_slice<T: FastWrite>(&mut self, data: &[T]) -> &mut Self {
+        self.print_slice(data, b' ').newline()
+    }
+
+    ///
+    /// One element per line
+    ///
+    pub fn println_lines<T: FastWrite>(&mut self, data: &[T]) -> &mut Self {
+        for x in data {
+            self.println(x);
+        }
+        self
+    }
+
+    ///
+    /// x rounded to `precision` digits after the decimal point, half away from zero
+    ///
+    pub fn float(&mut self, x: f64, precision: usize) -> &mut Self {
+        let scale = 10u64.checked_pow(precision as u32).map(|x| x as f64);
+        match scale {
+            Some(scale) if x.is_finite() && x.abs() * scale < 1e18 => {
+                let scaled = (x.abs() * scale).round() as u64;
+                let scale = scale as u64;
+                if x < 0.0 && scaled > 0 {
+                    self.buf.push(b'-');
+                }
+                write_u64(&mut self.buf, scaled / scale);
+                if precision > 0 {
+                    self.buf.push(b'.');
+                    let start = self.buf.len();
+                    write_u64(&mut self.buf, scaled % scale);
+                    let len = self.buf.len() - start;
+                    self.buf
+                        .splice(start..start, std::iter::repeat_n(b'0', precision - len));
+                }
+            }
+            _ => self
+                .buf
+                .extend_from_slice(format!("{:.*}", precision, x).as_bytes()),
+        }
+        self.check_buffer();
+        self
+    }
+
+    pub fn yes(&mut self) -> &mut Self {
+        self.println("YES")
+    }
+
+    pub fn no(&mut self) -> &mut Self {
+        self.println("NO")
+    }
+
+    pub fn yes_no(&mut self, b: bool) -> &mut Self {
+        self.println(if b { "YES" } else { "NO" })
+    }
+
+    ///
+    /// `Yes` or `No`
+    ///
+    pub fn yes_no_camel(&mut self, b: bool) -> &mut Self {
+        self.println(if b { "Yes" } else { "No" })
+    }
+}
+
+impl<W: Write> Write for FastOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        self.check_buffer();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.write_all(&self.buf)?;
+        self.buf.clear();
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for FastOutput<W> {
+    fn drop(&mut self) {
+        self.flush().ok();
+    }
+}
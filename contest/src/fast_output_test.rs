@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fast_output::*,
+        static_modint::{StaticModInt, MF998244353},
+    };
+
+    fn output(f: impl FnOnce(&mut FastOutput<&mut Vec<u8>>)) -> String {
+        let mut buf = Vec::new();
+        f(&mut FastOutput::new(&mut buf));
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_integer() {
+        for x in [0i64, 1, -1, 9, 10, 99, 100, 12345, -987654321, i64::MAX, i64::MIN] {
+            assert_eq!(format!("{}\n", x), output(|fo| { fo.println(x); }));
+        }
+        for x in [0u128, u64::MAX as u128 + 1, 10u128.pow(19), 10u128.pow(19) - 1, u128::MAX] {
+            assert_eq!(x.to_string(), output(|fo| { fo.print(x); }));
+        }
+        assert_eq!(i128::MIN.to_string(), output(|fo| { fo.print(i128::MIN); }));
+        let m = StaticModInt::<i32, MF998244353>::new(998244352);
+        assert_eq!("998244352 7", output(|fo| { fo.print(m).space().print(7u8); }));
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!("3.142 -0.50 0 0.000 -1.0 100.0000000000", output(|fo| {
+            fo.float(3.14159, 3).space().float(-0.5, 2).space().float(0.4, 0);
+            fo.space().float(-0.0001, 3).space().float(-0.96, 1).space().float(100.0, 10);
+        }));
+        assert_eq!("NaN", output(|fo| { fo.float(f64::NAN, 3); }));
+        assert_eq!(format!("{:.2}", 1e30), output(|fo| { fo.float(1e30, 2); }));
+    }
+
+    #[test]
+    fn test_slice_and_helpers() {
+        let s = output(|fo| {
+            fo.println_slice(&[1, 2, 3]).print_slice(&["a", "b"], b'\n').newline();
+            fo.println_lines(&[4, 5]).yes_no(true).yes_no(false).yes_no_camel(true);
+            std::io::Write::write_all(fo, b"raw").unwrap();
+        });
+        assert_eq!("1 2 3\na\nb\n4\n5\nYES\nNO\nYes\nraw", s);
+    }
+}
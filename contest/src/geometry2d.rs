@@ -0,0 +1,311 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::num_number::{FromNumber, Number};
+
+///
+/// Tolerance of floating point comparisons, it converts to zero for integers so all the
+/// predicates are exact there
+///
+pub const EPS: f64 = 1e-9;
+
+///
+/// -1, 0 or 1 by comparing x with EPS
+///
+pub fn sign<T: Number>(x: T) -> i8 {
+    let eps: T = FromNumber::from(EPS);
+    if x > eps {
+        1
+    } else if x < eps.negative() {
+        -1
+    } else {
+        0
+    }
+}
+
+///
+/// Point or vector in the plane, ordered by x then y
+///
+/// Products are computed in `T::HighPrecisionType`, so i64 coordinates up to about 1e18
+/// never overflow in cross and dot products
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point<T: Number> {
+    pub x: T,
+    pub y: T,
+}
+
+pub type Vector<T> = Point<T>;
+
+impl<T: Number> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T::HighPrecisionType {
+        self.x.upgrade() * rhs.x.upgrade() + self.y.upgrade() * rhs.y.upgrade()
+    }
+
+    pub fn cross(&self, rhs: &Self) -> T::HighPrecisionType {
+        self.x.upgrade() * rhs.y.upgrade() - self.y.upgrade() * rhs.x.upgrade()
+    }
+
+    pub fn norm2(&self) -> T::HighPrecisionType {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.norm2().as_f64().sqrt()
+    }
+
+    pub fn dist2(&self, rhs: &Self) -> T::HighPrecisionType {
+        (*self - *rhs).norm2()
+    }
+
+    pub fn dist(&self, rhs: &Self) -> f64 {
+        (*self - *rhs).norm()
+    }
+
+    ///
+    /// Rotate by 90 degrees counterclockwise
+    ///
+    pub fn rotate90(&self) -> Self {
+        Self::new(self.y.negative(), self.x)
+    }
+
+    pub fn to_f64(&self) -> Point<f64> {
+        Point::new(self.x.as_f64(), self.y.as_f64())
+    }
+
+    ///
+    /// 0 for the angles in [0, pi), 1 for [pi, 2pi), the origin is in the first half
+    ///
+    pub fn half(&self) -> u8 {
+        if sign(self.y) < 0 || (sign(self.y) == 0 && sign(self.x) < 0) {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+impl Point<f64> {
+    ///
+    /// Rotate counterclockwise by angle in radians
+    ///
+    pub fn rotate(&self, angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new(self.x * c - self.y * s, self.x * s + self.y * c)
+    }
+
+    pub fn unit(&self) -> Self {
+        *self / self.norm()
+    }
+}
+
+impl<T: Number> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Number> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Number> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Number> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+///
+/// Compare vectors by polar angle in [0, 2pi), exact for integers
+///
+pub fn cmp_by_angle<T: Number>(a: &Vector<T>, b: &Vector<T>) -> std::cmp::Ordering {
+    a.half()
+        .cmp(&b.half())
+        .then_with(|| 0.cmp(&sign(a.cross(b))))
+}
+
+///
+/// 1 if a, b, c turn counterclockwise, -1 if clockwise and 0 if collinear
+///
+pub fn orientation<T: Number>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> i8 {
+    sign((*b - *a).cross(&(*c - *a)))
+}
+
+///
+/// Whether p lies on the closed segment ab
+///
+pub fn on_segment<T: Number>(p: &Point<T>, a: &Point<T>, b: &Point<T>) -> bool {
+    orientation(a, b, p) == 0 && sign((*a - *p).dot(&(*b - *p))) <= 0
+}
+
+///
+/// Whether the closed segments ab and cd share a point
+///
+pub fn segments_intersect<T: Number>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> bool {
+    let (d1, d2) = (orientation(a, b, c), orientation(a, b, d));
+    let (d3, d4) = (orientation(c, d, a), orientation(c, d, b));
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    on_segment(c, a, b) || on_segment(d, a, b) || on_segment(a, c, d) || on_segment(b, c, d)
+}
+
+///
+/// Intersection of the lines ab and cd, None if they are parallel
+///
+pub fn line_intersection<T: Number>(
+    a: &Point<T>,
+    b: &Point<T>,
+    c: &Point<T>,
+    d: &Point<T>,
+) -> Option<Point<f64>> {
+    let (ab, cd) = (*b - *a, *d - *c);
+    let den = ab.cross(&cd);
+    if sign(den) == 0 {
+        return None;
+    }
+    let t = (*c - *a).cross(&cd).as_f64() / den.as_f64();
+    Some(a.to_f64() + ab.to_f64() * t)
+}
+
+///
+/// Intersection point of the closed segments ab and cd if they cross at exactly one point
+///
+pub fn segment_intersection<T: Number>(
+    a: &Point<T>,
+    b: &Point<T>,
+    c: &Point<T>,
+    d: &Point<T>,
+) -> Option<Point<f64>> {
+    if !segments_intersect(a, b, c, d) {
+        return None;
+    }
+    line_intersection(a, b, c, d).or_else(|| {
+        // collinear, only a single touching endpoint is a unique intersection
+        let mut touch: Vec<Point<T>> = [*a, *b]
+            .into_iter()
+            .filter(|p| on_segment(p, c, d))
+            .chain([*c, *d].into_iter().filter(|p| on_segment(p, a, b)))
+            .collect();
+        touch.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        touch.dedup();
+        if touch.len() == 1 {
+            Some(touch[0].to_f64())
+        } else {
+            None
+        }
+    })
+}
+
+///
+/// Distance from p to the closed segment ab
+///
+pub fn distance_point_segment<T: Number>(p: &Point<T>, a: &Point<T>, b: &Point<T>) -> f64 {
+    let (ab, ap) = (*b - *a, *p - *a);
+    if a == b || sign(ab.dot(&ap)) <= 0 {
+        return p.dist(a);
+    }
+    if sign((*a - *b).dot(&(*p - *b))) <= 0 {
+        return p.dist(b);
+    }
+    ab.cross(&ap).as_f64().abs() / ab.norm()
+}
+
+///
+/// Distance between the closed segments ab and cd
+///
+pub fn distance_segment_segment<T: Number>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> f64 {
+    if segments_intersect(a, b, c, d) {
+        return 0.0;
+    }
+    distance_point_segment(a, c, d)
+        .min(distance_point_segment(b, c, d))
+        .min(distance_point_segment(c, a, b))
+        .min(distance_point_segment(d, a, b))
+}
+
+///
+/// Twice the signed area of the polygon, positive if the vertices go counterclockwise
+///
+pub fn area2<T: Number>(poly: &[Point<T>]) -> T::HighPrecisionType {
+    let n = poly.len();
+    let mut res = <T::HighPrecisionType as Number>::ZERO;
+    for i in 0..n {
+        res += poly[i].cross(&poly[(i + 1) % n]);
+    }
+    res
+}
+
+pub fn area<T: Number>(poly: &[Point<T>]) -> f64 {
+    area2(poly).as_f64().abs() / 2.0
+}
+
+///
+/// Center of mass of the polygon region, the polygon must have non-zero area
+///
+pub fn centroid<T: Number>(poly: &[Point<T>]) -> Point<f64> {
+    let n = poly.len();
+    let (mut x, mut y, mut a) = (0.0, 0.0, 0.0);
+    for i in 0..n {
+        let (p, q) = (poly[i].to_f64(), poly[(i + 1) % n].to_f64());
+        let cross = poly[i].cross(&poly[(i + 1) % n]).as_f64();
+        x += (p.x + q.x) * cross;
+        y += (p.y + q.y) * cross;
+        a += cross;
+    }
+    Point::new(x / (3.0 * a), y / (3.0 * a))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Containment {
+    Outside,
+    OnBoundary,
+    Inside,
+}
+
+///
+/// Locate p against a simple polygon given in either order by winding number, O(n)
+///
+pub fn point_in_polygon<T: Number>(p: &Point<T>, poly: &[Point<T>]) -> Containment {
+    let n = poly.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (&poly[i], &poly[(i + 1) % n]);
+        if on_segment(p, a, b) {
+            return Containment::OnBoundary;
+        }
+        if a.y <= p.y && p.y < b.y && orientation(a, b, p) > 0 {
+            winding += 1;
+        } else if b.y <= p.y && p.y < a.y && orientation(a, b, p) < 0 {
+            winding -= 1;
+        }
+    }
+    if winding != 0 {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
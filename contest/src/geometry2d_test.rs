@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::{geometry2d::*, num_float::float, rand::Rng};
+
+    fn p(x: i64, y: i64) -> Point<i64> {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert_eq!(1, orientation(&p(0, 0), &p(1, 0), &p(0, 1)));
+        assert_eq!(-1, orientation(&p(0, 0), &p(0, 1), &p(1, 0)));
+        assert_eq!(0, orientation(&p(0, 0), &p(1, 1), &p(3, 3)));
+        let big = 1_000_000_000_000_000_000;
+        assert_eq!(1, orientation(&p(-big, -big), &p(big, big - 1), &p(big - 1, big)));
+        assert!(segments_intersect(&p(0, 0), &p(2, 2), &p(0, 2), &p(2, 0)));
+        assert!(segments_intersect(&p(0, 0), &p(2, 2), &p(2, 2), &p(3, 0)));
+        assert!(segments_intersect(&p(0, 0), &p(2, 0), &p(1, 0), &p(3, 0)));
+        assert!(!segments_intersect(&p(0, 0), &p(1, 0), &p(2, 0), &p(3, 0)));
+        assert!(!segments_intersect(&p(0, 0), &p(1, 1), &p(0, 1), &p(1, 2)));
+        assert_eq!(Some(Point::new(1.0, 1.0)), segment_intersection(&p(0, 0), &p(2, 2), &p(0, 2), &p(2, 0)));
+        assert_eq!(Some(Point::new(2.0, 0.0)), segment_intersection(&p(0, 0), &p(2, 0), &p(2, 0), &p(3, 0)));
+        assert_eq!(None, segment_intersection(&p(0, 0), &p(2, 0), &p(1, 0), &p(3, 0)));
+        assert!((distance_point_segment(&p(1, 1), &p(0, 0), &p(2, 0)) - 1.0).abs() < 1e-9);
+        assert!((distance_point_segment(&p(3, 4), &p(-2, 0), &p(0, 0)) - 5.0).abs() < 1e-9);
+        assert!((distance_segment_segment(&p(0, 0), &p(1, 0), &p(0, 2), &p(5, 2)) - 2.0).abs() < 1e-9);
+
+        let f = |x: f64, y: f64| Point::new(float::from(x), float::from(y));
+        assert_eq!(0, orientation(&f(0.0, 0.0), &f(0.1, 0.2), &f(0.3, 0.6)));
+        assert!(on_segment(&f(0.1 + 0.2, 0.0), &f(0.0, 0.0), &f(0.3, 0.0)));
+    }
+
+    #[test]
+    fn test_polygon() {
+        let square = vec![p(0, 0), p(4, 0), p(4, 4), p(0, 4)];
+        assert_eq!(32, area2(&square));
+        assert_eq!(-32, area2(&square.iter().rev().copied().collect::<Vec<_>>()));
+        assert_eq!(Point::new(2.0, 2.0), centroid(&square));
+        let triangle = vec![p(0, 0), p(3, 0), p(0, 3)];
+        assert_eq!(Point::new(1.0, 1.0), centroid(&triangle));
+        assert_eq!(4.5, area(&triangle));
+
+        // a U shape
+        let poly = vec![p(0, 0), p(6, 0), p(6, 6), p(4, 6), p(4, 2), p(2, 2), p(2, 6), p(0, 6)];
+        let inside = |x: i64, y: i64| {
+            let in_box = 0 < x && x < 6 && 0 < y && y < 6;
+            let in_notch = 2 <= x && x <= 4 && 2 <= y;
+            in_box && !in_notch
+        };
+        let boundary = |x: i64, y: i64| {
+            poly.iter()
+                .zip(poly.iter().cycle().skip(1))
+                .any(|(a, b)| on_segment(&p(x, y), a, b))
+        };
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..500 {
+            let (x, y) = (rng.range_i64(-1, 7), rng.range_i64(-1, 7));
+            let expect = if boundary(x, y) {
+                Containment::OnBoundary
+            } else if inside(x, y) {
+                Containment::Inside
+            } else {
+                Containment::Outside
+            };
+            assert_eq!(expect, point_in_polygon(&p(x, y), &poly));
+            let rev: Vec<Point<i64>> = poly.iter().rev().copied().collect();
+            assert_eq!(expect, point_in_polygon(&p(x, y), &rev));
+        }
+    }
+}
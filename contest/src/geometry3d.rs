@@ -0,0 +1,267 @@
+use std::{
+    collections::HashSet,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{geometry2d::sign, num_number::Number};
+
+///
+/// Point or vector in space, ordered by x, y then z
+///
+/// Cross products are computed in `T::HighPrecisionType`, so triple products of i64
+/// coordinates up to about 1e9 never overflow
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point3<T: Number> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+pub type Vector3<T> = Point3<T>;
+
+impl<T: Number> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn upgrade(&self) -> Point3<T::HighPrecisionType> {
+        Point3::new(self.x.upgrade(), self.y.upgrade(), self.z.upgrade())
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T::HighPrecisionType {
+        let (a, b) = (self.upgrade(), rhs.upgrade());
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    pub fn cross(&self, rhs: &Self) -> Point3<T::HighPrecisionType> {
+        let (a, b) = (self.upgrade(), rhs.upgrade());
+        Point3::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x)
+    }
+
+    pub fn norm2(&self) -> T::HighPrecisionType {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.norm2().as_f64().sqrt()
+    }
+
+    pub fn dist2(&self, rhs: &Self) -> T::HighPrecisionType {
+        (*self - *rhs).norm2()
+    }
+
+    pub fn dist(&self, rhs: &Self) -> f64 {
+        (*self - *rhs).norm()
+    }
+
+    pub fn to_f64(&self) -> Point3<f64> {
+        Point3::new(self.x.as_f64(), self.y.as_f64(), self.z.as_f64())
+    }
+}
+
+impl<T: Number> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Number> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Number> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Number> Div<T> for Point3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+///
+/// a . (b x c), six times the signed volume of the tetrahedron spanned by a, b and c
+///
+pub fn triple<T: Number>(a: &Vector3<T>, b: &Vector3<T>, c: &Vector3<T>) -> T::HighPrecisionType {
+    let (a, n) = (a.upgrade(), b.cross(c));
+    a.x * n.x + a.y * n.y + a.z * n.z
+}
+
+///
+/// 1 if d is on the side of the plane abc where (b - a) x (c - a) points, -1 on the other
+/// side and 0 if coplanar
+///
+pub fn orientation3<T: Number>(a: &Point3<T>, b: &Point3<T>, c: &Point3<T>, d: &Point3<T>) -> i8 {
+    sign(triple(&(*d - *a), &(*b - *a), &(*c - *a)))
+}
+
+///
+/// Distance from p to the plane through a, b and c, which must not be collinear
+///
+pub fn distance_point_plane<T: Number>(p: &Point3<T>, a: &Point3<T>, b: &Point3<T>, c: &Point3<T>) -> f64 {
+    let n = (*b - *a).cross(&(*c - *a));
+    triple(&(*p - *a), &(*b - *a), &(*c - *a)).as_f64().abs() / n.norm()
+}
+
+///
+/// Distance from p to the line through a and b, a != b
+///
+pub fn distance_point_line<T: Number>(p: &Point3<T>, a: &Point3<T>, b: &Point3<T>) -> f64 {
+    let ab = *b - *a;
+    ab.cross(&(*p - *a)).norm() / ab.norm()
+}
+
+///
+/// Distance from p to the closed segment ab
+///
+pub fn distance_point_segment<T: Number>(p: &Point3<T>, a: &Point3<T>, b: &Point3<T>) -> f64 {
+    let (ab, ap) = (*b - *a, *p - *a);
+    if a == b || sign(ab.dot(&ap)) <= 0 {
+        return p.dist(a);
+    }
+    if sign((*a - *b).dot(&(*p - *b))) <= 0 {
+        return p.dist(b);
+    }
+    distance_point_line(p, a, b)
+}
+
+///
+/// Distance between the lines ab and cd, a != b and c != d
+///
+pub fn distance_line_line<T: Number>(a: &Point3<T>, b: &Point3<T>, c: &Point3<T>, d: &Point3<T>) -> f64 {
+    let (ab, cd) = (*b - *a, *d - *c);
+    let n = ab.cross(&cd);
+    if sign(n.norm2()) == 0 {
+        return distance_point_line(c, a, b);
+    }
+    triple(&(*c - *a), &ab, &cd).as_f64().abs() / n.norm()
+}
+
+///
+/// Distance between the closed segments ab and cd
+///
+pub fn distance_segment_segment<T: Number>(a: &Point3<T>, b: &Point3<T>, c: &Point3<T>, d: &Point3<T>) -> f64 {
+    let endpoints = distance_point_segment(a, c, d)
+        .min(distance_point_segment(b, c, d))
+        .min(distance_point_segment(c, a, b))
+        .min(distance_point_segment(d, a, b));
+    // the distance is a convex function of the two parameters, so unless its unconstrained
+    // minimum lies inside [0, 1]^2 the answer is on the boundary, that is at an endpoint
+    let (u, v, w) = ((*b - *a).to_f64(), (*d - *c).to_f64(), (*a - *c).to_f64());
+    let (uu, uv, vv, uw, vw) = (u.dot(&u), u.dot(&v), v.dot(&v), u.dot(&w), v.dot(&w));
+    let den = uu * vv - uv * uv;
+    if den <= crate::geometry2d::EPS * uu * vv {
+        return endpoints;
+    }
+    let (s, t) = ((uv * vw - vv * uw) / den, (uu * vw - uv * uw) / den);
+    if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&t) {
+        return endpoints;
+    }
+    endpoints.min((w + u * s - v * t).norm())
+}
+
+///
+/// Incremental convex hull, O(n^2)
+///
+/// Return triangles of point indices, counterclockwise seen from outside, coplanar faces
+/// are triangulated arbitrarily, empty if all the points are coplanar
+///
+/// # Example
+///
+/// ```ignore
+/// let faces = convex_hull3(&points);
+/// let volume = volume(&points, &faces);
+/// ```
+///
+pub fn convex_hull3<T: Number>(points: &[Point3<T>]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let Some(i1) = (1..n).find(|&i| points[i] != points[0]) else {
+        return vec![];
+    };
+    let collinear = |i: usize| sign((points[i1] - points[0]).cross(&(points[i] - points[0])).norm2()) == 0;
+    let Some(i2) = (1..n).find(|&i| !collinear(i)) else {
+        return vec![];
+    };
+    let Some(i3) = (1..n).find(|&i| orientation3(&points[0], &points[i1], &points[i2], &points[i]) != 0) else {
+        return vec![];
+    };
+    let outward = |f: [usize; 3], inner: usize| {
+        if orientation3(&points[f[0]], &points[f[1]], &points[f[2]], &points[inner]) > 0 {
+            [f[0], f[2], f[1]]
+        } else {
+            f
+        }
+    };
+    let mut faces = vec![
+        outward([0, i1, i2], i3),
+        outward([0, i1, i3], i2),
+        outward([0, i2, i3], i1),
+        outward([i1, i2, i3], 0),
+    ];
+    for i in 1..n {
+        if i == i1 || i == i2 || i == i3 {
+            continue;
+        }
+        let p = &points[i];
+        let (visible, hidden): (Vec<[usize; 3]>, Vec<[usize; 3]>) = faces
+            .into_iter()
+            .partition(|f| orientation3(&points[f[0]], &points[f[1]], &points[f[2]], p) > 0);
+        faces = hidden;
+        if visible.is_empty() {
+            continue;
+        }
+        let edges: HashSet<(usize, usize)> = visible
+            .iter()
+            .flat_map(|f| [(f[0], f[1]), (f[1], f[2]), (f[2], f[0])])
+            .collect();
+        for &(u, v) in &edges {
+            if !edges.contains(&(v, u)) {
+                faces.push([u, v, i]);
+            }
+        }
+    }
+    faces
+}
+
+///
+/// Surface area of a polyhedron given by triangular faces
+///
+pub fn surface_area<T: Number>(points: &[Point3<T>], faces: &[[usize; 3]]) -> f64 {
+    faces
+        .iter()
+        .map(|f| (points[f[1]] - points[f[0]]).cross(&(points[f[2]] - points[f[0]])).norm() / 2.0)
+        .sum()
+}
+
+///
+/// Six times the signed volume of a closed polyhedron given by triangular faces,
+/// positive if they are counterclockwise seen from outside
+///
+pub fn volume6<T: Number>(points: &[Point3<T>], faces: &[[usize; 3]]) -> T::HighPrecisionType {
+    let mut res = <T::HighPrecisionType as Number>::ZERO;
+    for f in faces {
+        res += triple(&points[f[0]], &points[f[1]], &points[f[2]]);
+    }
+    res
+}
+
+pub fn volume<T: Number>(points: &[Point3<T>], faces: &[[usize; 3]]) -> f64 {
+    volume6(points, faces).as_f64().abs() / 6.0
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{geometry3d::*, rand::Rng};
+
+    fn random_point(rng: &mut Rng, m: i64) -> Point3<i64> {
+        Point3::new(rng.range_i64(-m, m), rng.range_i64(-m, m), rng.range_i64(-m, m))
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_products_and_distances() {
+        let (x, y, z) = (Point3::new(1i64, 0, 0), Point3::new(0, 1, 0), Point3::new(0, 0, 1));
+        assert_eq!(z.upgrade(), x.cross(&y));
+        assert_eq!(0, x.dot(&y));
+        assert_eq!(1, triple(&x, &y, &z));
+        let o = Point3::origin();
+        assert_eq!(1, orientation3(&o, &x, &y, &z));
+        assert_eq!(-1, orientation3(&o, &y, &x, &z));
+        let p = Point3::new(3, 4, 5);
+        assert!(close(5.0, distance_point_plane(&p, &o, &x, &y)));
+        assert!(close(5.0, distance_point_line(&p, &o, &z)));
+        assert!(close(41f64.sqrt(), distance_point_segment(&p, &o, &z)));
+        assert!(close(5.0, distance_point_segment(&p, &o, &(z * 6))));
+        assert!(close(2.0, distance_line_line(&o, &x, &Point3::new(0, 0, 2), &Point3::new(0, 1, 2))));
+        assert!(close(3.0, distance_line_line(&o, &x, &Point3::new(0, 3, 0), &Point3::new(5, 3, 0))));
+        // skew segments whose closest points are interior
+        let (a, b) = (Point3::new(-1, 0, 0), Point3::new(1, 0, 0));
+        let (c, d) = (Point3::new(0, -1, 2), Point3::new(0, 1, 2));
+        assert!(close(2.0, distance_segment_segment(&a, &b, &c, &d)));
+    }
+
+    #[test]
+    fn test_segment_distance_random() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let [a, b, c, d] = [(); 4].map(|_| random_point(&mut rng, 5));
+            let (a, b, c, d) = (a.to_f64(), b.to_f64(), c.to_f64(), d.to_f64());
+            let steps = 100;
+            let mut best = f64::MAX;
+            for i in 0..=steps {
+                let p = a + (b - a) * (i as f64 / steps as f64);
+                best = best.min(distance_point_segment(&p, &c, &d));
+            }
+            let res = distance_segment_segment(&a, &b, &c, &d);
+            assert!(res <= best + 1e-9);
+            assert!(best - res < 0.2);
+        }
+    }
+
+    #[test]
+    fn test_cube() {
+        let mut points = vec![];
+        for x in 0..=2 {
+            for y in 0..=2 {
+                for z in 0..=2 {
+                    points.push(Point3::new(x as i64, y, z));
+                }
+            }
+        }
+        let faces = convex_hull3(&points);
+        assert_eq!(48, volume6(&points, &faces));
+        assert!(close(8.0, volume(&points, &faces)));
+        assert!(close(24.0, surface_area(&points, &faces)));
+        assert!(convex_hull3(&points[..9]).is_empty());
+    }
+
+    #[test]
+    fn test_convex_hull3() {
+        let mut rng = Rng::new_with_seed(1);
+        for _ in 0..200 {
+            let n = rng.range_usize(4, 40);
+            let m = rng.range_i64(1, 10);
+            let points: Vec<Point3<i64>> = (0..n).map(|_| random_point(&mut rng, m)).collect();
+            let faces = convex_hull3(&points);
+            if faces.is_empty() {
+                let coplanar = (0..n).all(|i| {
+                    (0..n).all(|j| (0..n).all(|k| triple(&(points[j] - points[i]), &(points[k] - points[i]), &(points[0] - points[i])) == 0))
+                });
+                assert!(coplanar);
+                continue;
+            }
+            // every point lies inside or on every face
+            for f in &faces {
+                assert_ne!(0, (points[f[1]] - points[f[0]]).cross(&(points[f[2]] - points[f[0]])).norm2());
+                for p in &points {
+                    assert!(orientation3(&points[f[0]], &points[f[1]], &points[f[2]], p) <= 0);
+                }
+            }
+            // closed surface, every directed edge matches exactly one reversed edge
+            let edges: Vec<(usize, usize)> = faces
+                .iter()
+                .flat_map(|f| [(f[0], f[1]), (f[1], f[2]), (f[2], f[0])])
+                .collect();
+            let set: HashSet<(usize, usize)> = edges.iter().copied().collect();
+            assert_eq!(edges.len(), set.len());
+            for &(u, v) in &edges {
+                assert!(set.contains(&(v, u)));
+            }
+            // the volume does not depend on the apex of the tetrahedra
+            assert!(volume6(&points, &faces) > 0);
+            let o = points[faces[0][0]];
+            let fan: i128 = faces
+                .iter()
+                .map(|f| triple(&(points[f[0]] - o), &(points[f[1]] - o), &(points[f[2]] - o)))
+                .sum();
+            assert_eq!(fan, volume6(&points, &faces));
+        }
+    }
+}
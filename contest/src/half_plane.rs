@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+
+use crate::{
+    geometry2d::{cmp_by_angle, sign, Point, Vector},
+    num_number::Number,
+    shuffle::shuffle,
+};
+
+///
+/// Closed half-plane on the left of the directed line through p with direction d
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct HalfPlane<T: Number> {
+    pub p: Point<T>,
+    pub d: Vector<T>,
+}
+
+impl<T: Number> HalfPlane<T> {
+    ///
+    /// Left of the directed line from a to b
+    ///
+    pub fn new(a: Point<T>, b: Point<T>) -> Self {
+        Self { p: a, d: b - a }
+    }
+
+    pub fn with_direction(p: Point<T>, d: Vector<T>) -> Self {
+        Self { p, d }
+    }
+
+    ///
+    /// 1 if x is strictly inside, 0 on the boundary and -1 outside
+    ///
+    pub fn side(&self, x: &Point<T>) -> i8 {
+        sign(self.d.cross(&(*x - self.p)))
+    }
+
+    ///
+    /// Intersection of the boundary lines, None if they are parallel
+    ///
+    pub fn intersection(&self, rhs: &Self) -> Option<Point<f64>> {
+        let den = self.d.cross(&rhs.d);
+        if sign(den) == 0 {
+            return None;
+        }
+        let t = (rhs.p - self.p).cross(&rhs.d).as_f64() / den.as_f64();
+        Some(self.p.to_f64() + self.d.to_f64() * t)
+    }
+
+    ///
+    /// Side of the intersection of the boundaries of a and b against self, without
+    /// division so it's exact for integers as long as degree 4 products of coordinates
+    /// fit in `T::HighPrecisionType`, that is |coordinate| <= 1e9 for i64
+    ///
+    fn side_of_intersection(&self, a: &Self, b: &Self) -> i8 {
+        let den = a.d.cross(&b.d);
+        let num = (b.p - a.p).cross(&b.d);
+        let s = self.d.cross(&(a.p - self.p)) * den + self.d.cross(&a.d) * num;
+        sign(s) * sign(den)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum HalfPlaneIntersection {
+    ///
+    /// the intersection has zero area
+    ///
+    Empty,
+    Unbounded,
+    ///
+    /// vertices counterclockwise
+    ///
+    Bounded(Vec<Point<f64>>),
+}
+
+///
+/// Intersection of closed half-planes, O(n log n)
+///
+/// # Example
+///
+/// ```ignore
+/// let square = [(0, 0), (1, 0), (1, 1), (0, 1)].map(|(x, y)| Point::new(x, y));
+/// let planes: Vec<HalfPlane<i64>> = (0..4).map(|i| HalfPlane::new(square[i], square[(i + 1) % 4])).collect();
+/// assert_eq!(4, match half_plane_intersection(&planes) {
+///     HalfPlaneIntersection::Bounded(poly) => poly.len(),
+///     _ => 0,
+/// });
+/// ```
+///
+pub fn half_plane_intersection<T: Number>(planes: &[HalfPlane<T>]) -> HalfPlaneIntersection {
+    let mut planes = planes.to_vec();
+    planes.sort_by(|a, b| cmp_by_angle(&a.d, &b.d).then_with(|| a.side(&b.p).cmp(&0)));
+    // keep the most restrictive one of the same direction, that is the first one
+    planes.dedup_by(|b, a| cmp_by_angle(&a.d, &b.d) == Ordering::Equal);
+    let n = planes.len();
+    if n == 0 {
+        return HalfPlaneIntersection::Unbounded;
+    }
+    // a gap of at least pi between consecutive directions means the region is unbounded
+    // unless two opposite half-planes don't overlap
+    for i in 0..n {
+        let (a, b) = (&planes[i], &planes[(i + 1) % n]);
+        let cross = sign(a.d.cross(&b.d));
+        if n == 1 || cross < 0 {
+            return HalfPlaneIntersection::Unbounded;
+        }
+        if cross == 0 {
+            return if a.side(&b.p) > 0 {
+                HalfPlaneIntersection::Unbounded
+            } else {
+                HalfPlaneIntersection::Empty
+            };
+        }
+    }
+    let mut dq: Vec<HalfPlane<T>> = Vec::with_capacity(n);
+    let mut head = 0;
+    for h in planes {
+        while dq.len() - head >= 2 && h.side_of_intersection(&dq[dq.len() - 2], &dq[dq.len() - 1]) <= 0 {
+            dq.pop();
+        }
+        while dq.len() - head >= 2 && h.side_of_intersection(&dq[head], &dq[head + 1]) <= 0 {
+            head += 1;
+        }
+        if dq.len() > head && sign(dq[dq.len() - 1].d.cross(&h.d)) <= 0 {
+            return HalfPlaneIntersection::Empty;
+        }
+        dq.push(h);
+    }
+    while dq.len() - head >= 3 && dq[head].side_of_intersection(&dq[dq.len() - 2], &dq[dq.len() - 1]) <= 0 {
+        dq.pop();
+    }
+    while dq.len() - head >= 3 && dq[dq.len() - 1].side_of_intersection(&dq[head], &dq[head + 1]) <= 0 {
+        head += 1;
+    }
+    let dq = &dq[head..];
+    let m = dq.len();
+    if m < 3 || sign(dq[m - 1].d.cross(&dq[0].d)) <= 0 {
+        return HalfPlaneIntersection::Empty;
+    }
+    HalfPlaneIntersection::Bounded(
+        (0..m)
+            .map(|i| dq[i].intersection(&dq[(i + 1) % m]).unwrap())
+            .collect(),
+    )
+}
+
+///
+/// Seidel's randomized incremental algorithm, maximize c . x subject to all the half-planes
+/// and |x|, |y| <= bound, expected O(n)
+///
+/// None if infeasible, an optimum touching the bounding box means the LP is unbounded
+///
+/// The optimum is kept as the intersection of two constraint boundaries and every test is
+/// done on T like `half_plane_intersection`, so it's exact for integers as long as degree 4
+/// products of coordinates and bound fit in `T::HighPrecisionType`, that is up to 1e9 for
+/// i64. Only the returned point is converted to f64
+///
+pub fn seidel_lp<T: Number>(planes: &[HalfPlane<T>], c: Vector<T>, bound: T) -> Option<Point<f64>> {
+    let (zero, one, m) = (T::ZERO, T::ONE, bound);
+    // x <= m, y <= m, x >= -m and y >= -m come first and are never shuffled
+    let mut all = vec![
+        HalfPlane::with_direction(Point::new(m, zero), Vector::new(zero, one)),
+        HalfPlane::with_direction(Point::new(zero, m), Vector::new(one.negative(), zero)),
+        HalfPlane::with_direction(Point::new(m.negative(), zero), Vector::new(zero, one.negative())),
+        HalfPlane::with_direction(Point::new(zero, m.negative()), Vector::new(one, zero)),
+    ];
+    let mut planes = planes.to_vec();
+    shuffle(&mut planes);
+    all.extend(planes);
+    // the optimum so far is the intersection of the boundaries of all[a] and all[b]
+    let mut a = if sign(c.x) >= 0 { 0 } else { 2 };
+    let mut b = if sign(c.y) >= 0 { 1 } else { 3 };
+    for i in 4..all.len() {
+        let h = all[i];
+        if h.side_of_intersection(&all[a], &all[b]) >= 0 {
+            continue;
+        }
+        // the optimum of the first i + 1 constraints lies on h.p + h.d * t, the bounds of t
+        // are num / den with den > 0 and the index of the constraint giving them
+        let mut lo: Option<(T::HighPrecisionType, T::HighPrecisionType, usize)> = None;
+        let mut hi: Option<(T::HighPrecisionType, T::HighPrecisionType, usize)> = None;
+        for (j, g) in all[..i].iter().enumerate() {
+            // p * t + q >= 0
+            let (p, q) = (g.d.cross(&h.d), g.d.cross(&(h.p - g.p)));
+            match sign(p) {
+                0 if sign(q) < 0 => return None,
+                0 => {}
+                1 => {
+                    let (num, den) = (q.negative(), p);
+                    if lo.is_none_or(|(n, d, _)| num * d > n * den) {
+                        lo = Some((num, den, j));
+                    }
+                }
+                _ => {
+                    let (num, den) = (q, p.negative());
+                    if hi.is_none_or(|(n, d, _)| num * d < n * den) {
+                        hi = Some((num, den, j));
+                    }
+                }
+            }
+        }
+        // h.d isn't zero as h is violated, so the bounding box limits t on both sides
+        let ((ln, ld, lj), (hn, hd, hj)) = (lo.unwrap(), hi.unwrap());
+        if ln * hd > hn * ld {
+            return None;
+        }
+        a = i;
+        b = if sign(c.dot(&h.d)) > 0 { hj } else { lj };
+    }
+    all[a].intersection(&all[b])
+}
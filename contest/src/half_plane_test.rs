@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry2d::*,
+        half_plane::*,
+        num_bigint::I256,
+        num_fraction::Fraction,
+        num_number::{FromNumber, Number},
+        rand::Rng,
+    };
+
+    const BOX: f64 = 1e4;
+
+    fn random_plane(rng: &mut Rng) -> HalfPlane<i64> {
+        loop {
+            let a = Point::new(rng.range_i64(-5, 5), rng.range_i64(-5, 5));
+            let b = Point::new(rng.range_i64(-5, 5), rng.range_i64(-5, 5));
+            if a != b {
+                return HalfPlane::new(a, b);
+            }
+        }
+    }
+
+    fn clip(poly: &[Point<f64>], h: &HalfPlane<i64>) -> Vec<Point<f64>> {
+        let (p, d) = (h.p.to_f64(), h.d.to_f64());
+        let side = |x: &Point<f64>| d.cross(&(*x - p));
+        let mut res = Vec::new();
+        for i in 0..poly.len() {
+            let (a, b) = (poly[i], poly[(i + 1) % poly.len()]);
+            let (sa, sb) = (side(&a), side(&b));
+            if sa >= 0.0 {
+                res.push(a);
+            }
+            if (sa > 0.0 && sb < 0.0) || (sa < 0.0 && sb > 0.0) {
+                res.push(a + (b - a) * (sa / (sa - sb)));
+            }
+        }
+        res
+    }
+
+    fn area_f64(poly: &[Point<f64>]) -> f64 {
+        let n = poly.len();
+        (0..n).map(|i| poly[i].cross(&poly[(i + 1) % n])).sum::<f64>() / 2.0
+    }
+
+    #[test]
+    fn test_half_plane_intersection() {
+        let mut rng = Rng::new_with_seed(0);
+        let mut seen = [0; 3];
+        for _ in 0..2000 {
+            let n = rng.range_usize(1, 7);
+            let planes: Vec<HalfPlane<i64>> = (0..n).map(|_| random_plane(&mut rng)).collect();
+            let mut poly = vec![
+                Point::new(-BOX, -BOX),
+                Point::new(BOX, -BOX),
+                Point::new(BOX, BOX),
+                Point::new(-BOX, BOX),
+            ];
+            for h in &planes {
+                poly = clip(&poly, h);
+            }
+            let area = area_f64(&poly);
+            let touches_box = poly.iter().any(|p| p.x.abs() > BOX / 2.0 || p.y.abs() > BOX / 2.0);
+            match half_plane_intersection(&planes) {
+                HalfPlaneIntersection::Empty => {
+                    seen[0] += 1;
+                    assert!(area < 1e-6, "{:?} {}", planes, area);
+                }
+                HalfPlaneIntersection::Unbounded => {
+                    seen[1] += 1;
+                    assert!(touches_box, "{:?}", planes);
+                }
+                HalfPlaneIntersection::Bounded(res) => {
+                    seen[2] += 1;
+                    assert!(!touches_box);
+                    assert!((area - area_f64(&res)).abs() < 1e-6, "{:?}", planes);
+                    for p in &res {
+                        for h in &planes {
+                            assert!(h.d.to_f64().cross(&(*p - h.p.to_f64())) > -1e-6);
+                        }
+                    }
+                }
+            }
+        }
+        assert!(seen.iter().all(|&x| x > 50), "{:?}", seen);
+    }
+
+    #[test]
+    fn test_seidel_lp() {
+        let mut rng = Rng::new_with_seed(0);
+        let bound = 100;
+        for _ in 0..1000 {
+            let n = rng.range_usize(0, 8);
+            let planes: Vec<HalfPlane<i64>> = (0..n).map(|_| random_plane(&mut rng)).collect();
+            let c = Point::new(rng.range_i64(-3, 3), rng.range_i64(-3, 3));
+            let b = bound as f64;
+            let corners = [(-b, -b), (b, -b), (b, b), (-b, b)].map(|(x, y)| Point::new(x, y));
+            let mut lines: Vec<(Point<f64>, Point<f64>)> =
+                planes.iter().map(|h| (h.p.to_f64(), h.d.to_f64())).collect();
+            for i in 0..4 {
+                lines.push((corners[i], corners[(i + 1) % 4] - corners[i]));
+            }
+            let feasible = |x: &Point<f64>| {
+                x.x.abs() <= b + 1e-6
+                    && x.y.abs() <= b + 1e-6
+                    && planes.iter().all(|h| h.d.to_f64().cross(&(*x - h.p.to_f64())) >= -1e-6)
+            };
+            let mut best: Option<f64> = None;
+            for (i, &(p1, d1)) in lines.iter().enumerate() {
+                for &(p2, d2) in &lines[..i] {
+                    let den = d1.cross(&d2);
+                    if den.abs() < 1e-12 {
+                        continue;
+                    }
+                    let x = p1 + d1 * ((p2 - p1).cross(&d2) / den);
+                    if feasible(&x) {
+                        let v = c.to_f64().dot(&x);
+                        best = Some(best.map_or(v, |b: f64| b.max(v)));
+                    }
+                }
+            }
+            let res = seidel_lp(&planes, c, bound);
+            match (best, res) {
+                (None, None) => {}
+                (Some(v), Some(x)) => {
+                    assert!(feasible(&x));
+                    assert!((v - c.to_f64().dot(&x)).abs() < 1e-6);
+                }
+                _ => panic!("{:?} {:?} {:?}", planes, best, res),
+            }
+        }
+    }
+
+    #[test]
+    fn test_seidel_lp_large_coordinates() {
+        // planes passing within a few units of a common point far from the origin, whether
+        // the tiny region they leave is empty can't be told with a floating point tolerance
+        type Q = Fraction<I256>;
+        let q = |x: i64| Q::with_integer(FromNumber::from(x));
+        let mut rng = Rng::new_with_seed(1);
+        let bound = 1_000_000_000;
+        let mut seen = [0; 2];
+        for _ in 0..150 {
+            let base = Point::new(rng.range_i64(-500_000_000, 500_000_000), rng.range_i64(-500_000_000, 500_000_000));
+            let n = rng.range_usize(3, 6);
+            let planes: Vec<HalfPlane<i64>> = (0..n)
+                .map(|_| loop {
+                    let p = base + Point::new(rng.range_i64(-2, 2), rng.range_i64(-2, 2));
+                    let d = Point::new(rng.range_i64(-500_000_000, 500_000_000), rng.range_i64(-500_000_000, 500_000_000));
+                    if d != Point::new(0, 0) {
+                        return HalfPlane::with_direction(p, d);
+                    }
+                })
+                .collect();
+            let c = Point::new(rng.range_i64(-3, 3), rng.range_i64(-3, 3));
+            // exact optimum over the vertices of the arrangement, the box included
+            let (zero, m) = (0, bound);
+            let mut lines = planes.clone();
+            lines.push(HalfPlane::with_direction(Point::new(m, zero), Point::new(0, 1)));
+            lines.push(HalfPlane::with_direction(Point::new(zero, m), Point::new(-1, 0)));
+            lines.push(HalfPlane::with_direction(Point::new(-m, zero), Point::new(0, -1)));
+            lines.push(HalfPlane::with_direction(Point::new(zero, -m), Point::new(1, 0)));
+            let mut best: Option<Q> = None;
+            for (i, h1) in lines.iter().enumerate() {
+                for h2 in &lines[..i] {
+                    let den = h1.d.cross(&h2.d);
+                    if den == 0 {
+                        continue;
+                    }
+                    let t = Q::new(FromNumber::from((h2.p - h1.p).cross(&h2.d)), FromNumber::from(den));
+                    let (x, y) = (q(h1.p.x) + q(h1.d.x) * t, q(h1.p.y) + q(h1.d.y) * t);
+                    let feasible = lines
+                        .iter()
+                        .all(|g| q(g.d.x) * (y - q(g.p.y)) - q(g.d.y) * (x - q(g.p.x)) >= q(0));
+                    if feasible {
+                        let v = q(c.x) * x + q(c.y) * y;
+                        best = Some(best.map_or(v, |b| b.max(v)));
+                    }
+                }
+            }
+            let res = seidel_lp(&planes, c, bound);
+            assert_eq!(best.is_some(), res.is_some(), "{:?}", planes);
+            if let (Some(v), Some(x)) = (best, res) {
+                seen[1] += 1;
+                assert!((v.to_float().as_f64() - c.to_f64().dot(&x)).abs() < 1e-3);
+            } else {
+                seen[0] += 1;
+            }
+        }
+        assert!(seen.iter().all(|&x| x > 15), "{:?}", seen);
+    }
+}
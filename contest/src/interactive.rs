@@ -0,0 +1,329 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Read, Write},
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    fast_input::{FastInput, InputError},
+    stress::panic_message,
+};
+
+const STACK_SIZE: usize = 256 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Solution,
+    Interactor,
+}
+
+type Transcript = Arc<Mutex<Vec<(Side, String)>>>;
+
+///
+/// Read end of an in-memory pipe, blocks until the other side flushes
+///
+pub struct PipeReader {
+    rx: Receiver<Vec<u8>>,
+    buf: Vec<u8>,
+    pos: usize,
+    timeout: Duration,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let data = self.fill_buf()?;
+            let n = data.len().min(out.len());
+            out[..n].copy_from_slice(&data[..n]);
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for PipeReader {
+    ///
+    /// Empty slice once the write end is dropped, TimedOut error if nothing arrives in
+    /// time, which usually means the other side forgot to flush
+    ///
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.pos == self.buf.len() {
+            match self.rx.recv_timeout(self.timeout) {
+                Ok(data) => {
+                    self.buf = data;
+                    self.pos = 0;
+                }
+                Err(RecvTimeoutError::Disconnected) => return Ok(&[]),
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("nothing received in {:?}, is the output flushed?", self.timeout),
+                    ))
+                }
+            }
+        }
+        Ok(&self.buf[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+///
+/// Write end of an in-memory pipe, data reaches the reader only when flushed, just like
+/// a buffered stdout, and it's flushed when dropped
+///
+pub struct PipeWriter {
+    tx: Sender<Vec<u8>>,
+    buf: Vec<u8>,
+    side: Side,
+    transcript: Transcript,
+}
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let data = std::mem::take(&mut self.buf);
+        let mut transcript = self.transcript.lock().unwrap();
+        for line in String::from_utf8_lossy(&data).lines() {
+            transcript.push((self.side, line.to_string()));
+        }
+        self.tx
+            .send(data)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "the other side exited"))
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        self.flush().ok();
+    }
+}
+
+fn pipe(side: Side, transcript: &Transcript, timeout: Duration) -> (PipeWriter, PipeReader) {
+    let (tx, rx) = mpsc::channel();
+    (
+        PipeWriter {
+            tx,
+            buf: Vec::new(),
+            side,
+            transcript: transcript.clone(),
+        },
+        PipeReader {
+            rx,
+            buf: Vec::new(),
+            pos: 0,
+            timeout,
+        },
+    )
+}
+
+///
+/// Interactor side of the pipes, counts queries against the budget
+///
+pub struct Interaction {
+    fi: FastInput<PipeReader>,
+    fo: PipeWriter,
+    queries: usize,
+    query_limit: usize,
+}
+
+impl Interaction {
+    ///
+    /// Read a token sent by the solution, Err if it exits, sends garbage or doesn't
+    /// respond in time
+    ///
+    pub fn read<T: FromStr>(&mut self) -> Result<T, String> {
+        match self.fi.try_read::<String>() {
+            Ok(token) => token
+                .parse()
+                .map_err(|_| format!("can't parse `{}` sent by solution", token)),
+            Err(InputError::Eof) => Err("solution exited while a response is expected".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    ///
+    /// Send a line to the solution, flushed immediately
+    ///
+    pub fn write(&mut self, x: impl Display) {
+        writeln!(self.fo, "{}", x).ok();
+        self.fo.flush().ok();
+    }
+
+    ///
+    /// Count a query, Err once the budget is exceeded
+    ///
+    pub fn query(&mut self) -> Result<(), String> {
+        self.queries += 1;
+        if self.queries > self.query_limit {
+            Err(format!("query limit {} exceeded", self.query_limit))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn queries(&self) -> usize {
+        self.queries
+    }
+
+    ///
+    /// Err if the solution sends anything more
+    ///
+    pub fn expect_eof(&mut self) -> Result<(), String> {
+        match self.fi.try_read::<String>() {
+            Err(InputError::Eof) => Ok(()),
+            Ok(token) => Err(format!("unexpected `{}` after the interaction finished", token)),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+///
+/// Judge of an interactive problem, it knows the hidden test from input and talks with
+/// the solution through `Interaction`
+///
+pub trait Interactor: Send + Sync {
+    fn interact(&self, input: &str, io: &mut Interaction) -> Result<(), String>;
+}
+
+impl<F> Interactor for F
+where
+    F: Fn(&str, &mut Interaction) -> Result<(), String> + Send + Sync,
+{
+    fn interact(&self, input: &str, io: &mut Interaction) -> Result<(), String> {
+        self(input, io)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct InteractConfig {
+    pub query_limit: usize,
+    ///
+    /// time to wait for a response before blaming the other side
+    ///
+    pub timeout: Duration,
+    ///
+    /// time for the whole interaction, threads still running after it are abandoned
+    ///
+    pub time_limit: Duration,
+}
+
+impl Default for InteractConfig {
+    fn default() -> Self {
+        Self {
+            query_limit: usize::MAX,
+            timeout: Duration::from_secs(1),
+            time_limit: Duration::from_secs(10),
+        }
+    }
+}
+
+pub struct InteractFailure {
+    pub message: String,
+    ///
+    /// interactor panicked, which means the input is invalid rather than the solution is wrong,
+    /// anything the solution does wrong, including not responding, is reported by `Interaction`
+    /// as Err instead
+    ///
+    pub invalid: bool,
+    ///
+    /// lines sent by solution start with `>`, lines sent by interactor start with `<`
+    ///
+    pub transcript: String,
+}
+
+fn render_transcript(transcript: &Transcript) -> String {
+    let mut res = String::new();
+    for (side, line) in transcript.lock().unwrap().iter() {
+        res.push_str(if *side == Side::Solution { "> " } else { "< " });
+        res.push_str(line);
+        res.push('\n');
+    }
+    res
+}
+
+///
+/// Run solve on one thread and interactor on another, connected by in-memory pipes
+///
+pub fn interact<S>(
+    input: &str,
+    solve: S,
+    interactor: Arc<dyn Interactor>,
+    config: InteractConfig,
+) -> Result<(), InteractFailure>
+where
+    S: FnOnce(&mut FastInput<PipeReader>, &mut PipeWriter) + Send + 'static,
+{
+    let transcript: Transcript = Arc::new(Mutex::new(Vec::new()));
+    let (to_interactor, from_solution) = pipe(Side::Solution, &transcript, config.timeout);
+    let (to_solution, from_interactor) = pipe(Side::Interactor, &transcript, config.timeout);
+    let (tx, rx) = mpsc::channel();
+    let solution_tx = tx.clone();
+    let (invalid_tx, invalid_rx) = mpsc::channel();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let mut fi = FastInput::new(from_interactor);
+            let mut fo = to_interactor;
+            let res = panic::catch_unwind(AssertUnwindSafe(move || {
+                solve(&mut fi, &mut fo);
+                fo.flush().ok();
+            }));
+            let res = res.map_err(|e| format!("solution panicked: {}", panic_message(e)));
+            solution_tx.send(res).ok();
+        })
+        .unwrap();
+    let input = input.to_string();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let mut io = Interaction {
+                fi: FastInput::new(from_solution),
+                fo: to_solution,
+                queries: 0,
+                query_limit: config.query_limit,
+            };
+            let res = panic::catch_unwind(AssertUnwindSafe(move || interactor.interact(&input, &mut io)));
+            invalid_tx.send(res.is_err()).ok();
+            let res = res.unwrap_or_else(|e| Err(format!("interactor panicked: {}", panic_message(e))));
+            tx.send(res).ok();
+        })
+        .unwrap();
+    let mut errors = Vec::new();
+    for _ in 0..2 {
+        match rx.recv_timeout(config.time_limit) {
+            Ok(Ok(())) => {}
+            Ok(Err(msg)) => errors.push(msg),
+            Err(_) => {
+                errors.push(format!("interaction doesn't finish in {:?}", config.time_limit));
+                break;
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(InteractFailure {
+            message: errors.join("; "),
+            invalid: invalid_rx.try_recv().unwrap_or(false),
+            transcript: render_transcript(&transcript),
+        })
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, sync::Arc, time::Duration};
+
+    use crate::interactive::*;
+
+    ///
+    /// guess a hidden number in [1, n], the interactor answers `<`, `>` or `=`
+    ///
+    fn guess_number(input: &str, io: &mut Interaction) -> Result<(), String> {
+        let mut it = input.split_ascii_whitespace().map(|x| x.parse::<u64>().unwrap());
+        let (n, hidden) = (it.next().unwrap(), it.next().unwrap());
+        io.write(n);
+        loop {
+            io.query()?;
+            let x: u64 = io.read()?;
+            if x == hidden {
+                io.write("=");
+                return io.expect_eof();
+            }
+            io.write(if hidden < x { "<" } else { ">" });
+        }
+    }
+
+    fn config(query_limit: usize) -> InteractConfig {
+        InteractConfig {
+            query_limit,
+            timeout: Duration::from_millis(200),
+            ..InteractConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let solve = |fi: &mut crate::fast_input::FastInput<PipeReader>, fo: &mut PipeWriter| {
+            let n: u64 = fi.read();
+            let (mut l, mut r) = (1, n);
+            loop {
+                let m = (l + r) / 2;
+                writeln!(fo, "{}", m).unwrap();
+                fo.flush().unwrap();
+                let s: String = fi.read();
+                match s.as_str() {
+                    "=" => return,
+                    "<" => r = m - 1,
+                    _ => l = m + 1,
+                }
+            }
+        };
+        for hidden in 1..=20 {
+            let input = format!("20 {}", hidden);
+            assert!(interact(&input, solve, Arc::new(guess_number), config(5)).is_ok());
+        }
+        let linear = |fi: &mut crate::fast_input::FastInput<PipeReader>, fo: &mut PipeWriter| {
+            let n: u64 = fi.read();
+            for x in 1..=n {
+                writeln!(fo, "{}", x).unwrap();
+                fo.flush().unwrap();
+                let s: String = fi.read();
+                if s == "=" {
+                    return;
+                }
+            }
+        };
+        let failure = interact("20 7", linear, Arc::new(guess_number), config(5)).err().unwrap();
+        assert!(failure.message.contains("query limit 5 exceeded"));
+        assert!(failure.transcript.starts_with("< 20\n> 1\n< >\n"));
+    }
+
+    #[test]
+    fn test_forget_flush() {
+        let solve = |fi: &mut crate::fast_input::FastInput<PipeReader>, fo: &mut PipeWriter| {
+            let n: u64 = fi.read();
+            writeln!(fo, "{}", n).unwrap();
+            let _: String = fi.read();
+        };
+        let failure = interact("20 20", solve, Arc::new(guess_number), config(5)).err().unwrap();
+        assert!(failure.message.contains("is the output flushed?"));
+        assert!(!failure.invalid);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let solve = |fi: &mut crate::fast_input::FastInput<PipeReader>, fo: &mut PipeWriter| {
+            let n: u64 = fi.read();
+            writeln!(fo, "{}", n).unwrap();
+        };
+        let failure = interact("20 7", solve, Arc::new(guess_number), config(5)).err().unwrap();
+        assert!(failure.message.contains("solution exited"));
+        assert!(!failure.invalid);
+        // the interactor can't parse its own input
+        let failure = interact("20", solve, Arc::new(guess_number), config(5)).err().unwrap();
+        assert!(failure.message.contains("interactor panicked"));
+        assert!(failure.invalid);
+    }
+}
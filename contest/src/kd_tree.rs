@@ -0,0 +1,274 @@
+use crate::{algebraic_structure::Monoid, geometry2d::Point, num_number::Number};
+
+///
+/// (squared distance, i, j) of a closest pair of points by divide and conquer, exact for
+/// integers, O(n log n), None if there are less than two points
+///
+pub fn closest_pair<T: Number>(points: &[Point<T>]) -> Option<(T::HighPrecisionType, usize, usize)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| points[a].partial_cmp(&points[b]).unwrap());
+    let mut best = (points[order[0]].dist2(&points[order[1]]), order[0], order[1]);
+    let mut buf = vec![0; points.len()];
+    closest_pair_rec(points, &mut order, &mut buf, &mut best);
+    Some(best)
+}
+
+///
+/// Solve order sorted by x and leave it sorted by y
+///
+fn closest_pair_rec<T: Number>(
+    points: &[Point<T>],
+    order: &mut [usize],
+    buf: &mut [usize],
+    best: &mut (T::HighPrecisionType, usize, usize),
+) {
+    let n = order.len();
+    if n <= 1 {
+        return;
+    }
+    let mid = n / 2;
+    let mid_x = points[order[mid]].x;
+    let (left, right) = order.split_at_mut(mid);
+    closest_pair_rec(points, left, buf, best);
+    closest_pair_rec(points, right, buf, best);
+    // merge by y
+    let (mut i, mut j) = (0, mid);
+    for slot in buf[..n].iter_mut() {
+        if j == n || (i < mid && points[order[i]].y <= points[order[j]].y) {
+            *slot = order[i];
+            i += 1;
+        } else {
+            *slot = order[j];
+            j += 1;
+        }
+    }
+    order.copy_from_slice(&buf[..n]);
+    let mut strip: Vec<usize> = Vec::new();
+    for &a in order.iter() {
+        let dx = (points[a].x - mid_x).upgrade();
+        if dx * dx >= best.0 {
+            continue;
+        }
+        for &b in strip.iter().rev() {
+            let dy = (points[a].y - points[b].y).upgrade();
+            if dy * dy >= best.0 {
+                break;
+            }
+            let d = points[a].dist2(&points[b]);
+            if d < best.0 {
+                *best = (d, b, a);
+            }
+        }
+        strip.push(a);
+    }
+}
+
+///
+/// Static 2D tree over points carrying monoid values, O(n log n) to build
+///
+/// Rectangle queries take O(sqrt n) and nearest neighbour queries are fast on non
+/// adversarial data
+///
+/// # Example
+///
+/// ```ignore
+/// let tree = KdTree::new(&points, vec![1u64; points.len()]);
+/// let sum = tree.query(&Point::new(0, 0), &Point::new(10, 10));
+/// let nearest = tree.nearest(&Point::new(3, 4), 1)[0].1;
+/// ```
+///
+pub struct KdTree<T: Number, S: Monoid> {
+    points: Vec<Point<T>>,
+    values: Vec<S>,
+    index: Vec<usize>,
+    axis: Vec<u8>,
+    ///
+    /// bounding box and sum of the subtree rooted at each position
+    ///
+    lo: Vec<Point<T>>,
+    hi: Vec<Point<T>>,
+    sum: Vec<S>,
+}
+
+impl<T: Number> KdTree<T, usize> {
+    ///
+    /// Tree whose value of every point is 1, so that `query` counts points
+    ///
+    pub fn from_points(points: &[Point<T>]) -> Self {
+        Self::new(points, vec![1; points.len()])
+    }
+}
+
+impl<T: Number, S: Monoid> KdTree<T, S> {
+    pub fn new(points: &[Point<T>], values: Vec<S>) -> Self {
+        let n = points.len();
+        let mut res = Self {
+            points: points.to_vec(),
+            values,
+            index: (0..n).collect(),
+            axis: vec![0; n],
+            lo: points.to_vec(),
+            hi: points.to_vec(),
+            sum: vec![S::zero(); n],
+        };
+        res.build(0, n);
+        res
+    }
+
+    fn build(&mut self, l: usize, r: usize) {
+        if l >= r {
+            return;
+        }
+        let (mut lo, mut hi) = (self.points[l], self.points[l]);
+        for p in &self.points[l..r] {
+            lo = Point::new(if p.x < lo.x { p.x } else { lo.x }, if p.y < lo.y { p.y } else { lo.y });
+            hi = Point::new(if p.x > hi.x { p.x } else { hi.x }, if p.y > hi.y { p.y } else { hi.y });
+        }
+        let axis = if hi.x - lo.x >= hi.y - lo.y { 0 } else { 1 };
+        let key = |p: &Point<T>| if axis == 0 { (p.x, p.y) } else { (p.y, p.x) };
+        let m = (l + r) / 2;
+        let mut order: Vec<usize> = (l..r).collect();
+        order.select_nth_unstable_by(m - l, |&a, &b| {
+            key(&self.points[a]).partial_cmp(&key(&self.points[b])).unwrap()
+        });
+        let points: Vec<Point<T>> = order.iter().map(|&i| self.points[i]).collect();
+        let values: Vec<S> = order.iter().map(|&i| self.values[i]).collect();
+        let index: Vec<usize> = order.iter().map(|&i| self.index[i]).collect();
+        self.points[l..r].copy_from_slice(&points);
+        self.values[l..r].copy_from_slice(&values);
+        self.index[l..r].copy_from_slice(&index);
+        self.build(l, m);
+        self.build(m + 1, r);
+        let mut sum = self.values[m];
+        if l < m {
+            sum = self.sum[(l + m) / 2] + sum;
+        }
+        if m + 1 < r {
+            sum = sum + self.sum[(m + 1 + r) / 2];
+        }
+        self.axis[m] = axis;
+        self.lo[m] = lo;
+        self.hi[m] = hi;
+        self.sum[m] = sum;
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    ///
+    /// Sum of the values of the points in the closed rectangle [lo.x, hi.x] x [lo.y, hi.y]
+    ///
+    pub fn query(&self, lo: &Point<T>, hi: &Point<T>) -> S {
+        self.query_rec(0, self.len(), lo, hi)
+    }
+
+    fn query_rec(&self, l: usize, r: usize, lo: &Point<T>, hi: &Point<T>) -> S {
+        if l >= r {
+            return S::zero();
+        }
+        let m = (l + r) / 2;
+        let (blo, bhi) = (&self.lo[m], &self.hi[m]);
+        if bhi.x < lo.x || blo.x > hi.x || bhi.y < lo.y || blo.y > hi.y {
+            return S::zero();
+        }
+        if lo.x <= blo.x && bhi.x <= hi.x && lo.y <= blo.y && bhi.y <= hi.y {
+            return self.sum[m];
+        }
+        let p = &self.points[m];
+        let mut res = self.query_rec(l, m, lo, hi);
+        if lo.x <= p.x && p.x <= hi.x && lo.y <= p.y && p.y <= hi.y {
+            res = res + self.values[m];
+        }
+        res + self.query_rec(m + 1, r, lo, hi)
+    }
+
+    ///
+    /// Number of points in the closed rectangle [lo.x, hi.x] x [lo.y, hi.y]
+    ///
+    pub fn count(&self, lo: &Point<T>, hi: &Point<T>) -> usize {
+        self.count_rec(0, self.len(), lo, hi)
+    }
+
+    fn count_rec(&self, l: usize, r: usize, lo: &Point<T>, hi: &Point<T>) -> usize {
+        if l >= r {
+            return 0;
+        }
+        let m = (l + r) / 2;
+        let (blo, bhi) = (&self.lo[m], &self.hi[m]);
+        if bhi.x < lo.x || blo.x > hi.x || bhi.y < lo.y || blo.y > hi.y {
+            return 0;
+        }
+        if lo.x <= blo.x && bhi.x <= hi.x && lo.y <= blo.y && bhi.y <= hi.y {
+            return r - l;
+        }
+        let p = &self.points[m];
+        let inside = lo.x <= p.x && p.x <= hi.x && lo.y <= p.y && p.y <= hi.y;
+        self.count_rec(l, m, lo, hi) + inside as usize + self.count_rec(m + 1, r, lo, hi)
+    }
+
+    ///
+    /// Up to k nearest points to q as (squared distance, index) ordered by distance
+    ///
+    pub fn nearest(&self, q: &Point<T>, k: usize) -> Vec<(T::HighPrecisionType, usize)> {
+        let mut res = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.nearest_rec(0, self.len(), q, k, &mut res);
+        }
+        res
+    }
+
+    fn box_dist2(&self, m: usize, q: &Point<T>) -> T::HighPrecisionType {
+        let gap = |lo: T, hi: T, x: T| {
+            if x < lo {
+                (lo - x).upgrade()
+            } else if x > hi {
+                (x - hi).upgrade()
+            } else {
+                <T::HighPrecisionType as Number>::ZERO
+            }
+        };
+        let (lo, hi) = (&self.lo[m], &self.hi[m]);
+        let (dx, dy) = (gap(lo.x, hi.x, q.x), gap(lo.y, hi.y, q.y));
+        dx * dx + dy * dy
+    }
+
+    fn nearest_rec(
+        &self,
+        l: usize,
+        r: usize,
+        q: &Point<T>,
+        k: usize,
+        res: &mut Vec<(T::HighPrecisionType, usize)>,
+    ) {
+        if l >= r {
+            return;
+        }
+        let m = (l + r) / 2;
+        if res.len() == k && self.box_dist2(m, q) >= res[k - 1].0 {
+            return;
+        }
+        let d = self.points[m].dist2(q);
+        if res.len() < k || d < res[k - 1].0 {
+            let pos = res.partition_point(|x| x.0 <= d);
+            res.insert(pos, (d, self.index[m]));
+            res.truncate(k);
+        }
+        let p = &self.points[m];
+        let left_first = if self.axis[m] == 0 { q.x < p.x } else { q.y < p.y };
+        if left_first {
+            self.nearest_rec(l, m, q, k, res);
+            self.nearest_rec(m + 1, r, q, k, res);
+        } else {
+            self.nearest_rec(m + 1, r, q, k, res);
+            self.nearest_rec(l, m, q, k, res);
+        }
+    }
+}
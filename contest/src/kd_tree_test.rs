@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::{geometry2d::Point, kd_tree::*, rand::Rng};
+
+    fn random_points(rng: &mut Rng, n: usize, m: i64) -> Vec<Point<i64>> {
+        (0..n)
+            .map(|_| Point::new(rng.range_i64(-m, m), rng.range_i64(-m, m)))
+            .collect()
+    }
+
+    #[test]
+    fn test_closest_pair() {
+        let mut rng = Rng::new_with_seed(0);
+        assert_eq!(None, closest_pair::<i64>(&[Point::new(1, 1)]));
+        for _ in 0..300 {
+            let n = rng.range_usize(2, 60);
+            let m = rng.range_i64(1, 1000);
+            let points = random_points(&mut rng, n, m);
+            let mut best = i128::MAX;
+            for i in 0..n {
+                for j in i + 1..n {
+                    best = best.min(points[i].dist2(&points[j]));
+                }
+            }
+            let (d, i, j) = closest_pair(&points).unwrap();
+            assert_eq!(best, d);
+            assert_ne!(i, j);
+            assert_eq!(d, points[i].dist2(&points[j]));
+        }
+    }
+
+    #[test]
+    fn test_rectangle_queries() {
+        let mut rng = Rng::new_with_seed(1);
+        for _ in 0..100 {
+            let n = rng.range_usize(0, 80);
+            let m = rng.range_i64(1, 20);
+            let points = random_points(&mut rng, n, m);
+            let values: Vec<u64> = (0..n).map(|_| rng.limit_u64(1000)).collect();
+            let tree = KdTree::new(&points, values.clone());
+            let counter = KdTree::from_points(&points);
+            assert_eq!(n, tree.len());
+            for _ in 0..50 {
+                let (x1, x2) = (rng.range_i64(-m - 1, m + 1), rng.range_i64(-m - 1, m + 1));
+                let (y1, y2) = (rng.range_i64(-m - 1, m + 1), rng.range_i64(-m - 1, m + 1));
+                let (lo, hi) = (Point::new(x1.min(x2), y1.min(y2)), Point::new(x1.max(x2), y1.max(y2)));
+                let inside: Vec<usize> = (0..n)
+                    .filter(|&i| lo.x <= points[i].x && points[i].x <= hi.x && lo.y <= points[i].y && points[i].y <= hi.y)
+                    .collect();
+                assert_eq!(inside.iter().map(|&i| values[i]).sum::<u64>(), tree.query(&lo, &hi));
+                assert_eq!(inside.len(), tree.count(&lo, &hi));
+                assert_eq!(inside.len(), counter.query(&lo, &hi));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearest() {
+        let mut rng = Rng::new_with_seed(2);
+        for _ in 0..100 {
+            let n = rng.range_usize(1, 80);
+            let m = rng.range_i64(1, 50);
+            let points = random_points(&mut rng, n, m);
+            let tree = KdTree::from_points(&points);
+            for _ in 0..30 {
+                let q = Point::new(rng.range_i64(-m - 5, m + 5), rng.range_i64(-m - 5, m + 5));
+                let k = rng.range_usize(0, n + 2);
+                let mut expected: Vec<i128> = points.iter().map(|p| p.dist2(&q)).collect();
+                expected.sort();
+                expected.truncate(k);
+                let res = tree.nearest(&q, k);
+                assert_eq!(expected, res.iter().map(|x| x.0).collect::<Vec<_>>());
+                for (d, i) in res {
+                    assert_eq!(d, points[i].dist2(&q));
+                }
+            }
+        }
+    }
+}
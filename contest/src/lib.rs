@@ -3,6 +3,8 @@
 //io
 pub mod fast_input;
 pub mod fast_input_test;
+pub mod fast_output;
+pub mod fast_output_test;
 
 //math
 pub mod algebraic_structure;
@@ -51,6 +53,10 @@ pub mod enumerate_prime;
 pub mod bitset;
 pub mod complex;
 pub mod num_float;
+pub mod num_bigint;
+pub mod num_bigint_test;
+pub mod num_fraction;
+pub mod num_fraction_test;
 
 // ds
 pub mod dsu_compress_path;
@@ -91,6 +97,36 @@ pub mod two_sat_sparse;
 pub mod binary_search;
 pub mod maximum_independent_set;
 
+//string
+pub mod string;
+pub mod string_test;
+pub mod suffix_array;
+pub mod suffix_array_test;
+pub mod suffix_automaton;
+pub mod suffix_automaton_test;
+pub mod aho_corasick;
+pub mod aho_corasick_test;
+pub mod string_hash;
+pub mod string_hash_test;
+pub mod eertree;
+pub mod eertree_test;
+pub mod lyndon;
+pub mod lyndon_test;
+
+//geometry
+pub mod geometry2d;
+pub mod geometry2d_test;
+pub mod convex_hull;
+pub mod convex_hull_test;
+pub mod half_plane;
+pub mod half_plane_test;
+pub mod circle;
+pub mod circle_test;
+pub mod kd_tree;
+pub mod kd_tree_test;
+pub mod geometry3d;
+pub mod geometry3d_test;
+
 
 //util
 pub mod id;
@@ -113,10 +149,20 @@ pub mod bipartite_minimum_weight_match;
 pub mod bipartite_maximum_weight_match_km;
 
 //for contest
+pub mod checker;
+pub mod checker_test;
+pub mod interactive;
+pub mod interactive_test;
 pub mod macros;
+pub mod macros_test;
+pub mod samples;
+pub mod samples_test;
 pub mod solver;
 pub mod stress;
+pub mod stress_test;
 pub mod stress_external_member;
+pub mod stress_generator;
+pub mod stress_generator_test;
 
 //vector
 pub mod vector_binary_convolution;
@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use crate::suffix_array::SuffixArray;
+
+///
+/// Duval's algorithm, split s into lexicographically non-increasing Lyndon words,
+/// return (start, length) of every factor, O(n)
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(vec![(0, 2), (2, 2), (4, 1)], lyndon_factorization(b"ababa"));
+/// ```
+///
+pub fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<(usize, usize)> {
+    let n = s.len();
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            res.push((i, j - k));
+            i += j - k;
+        }
+    }
+    res
+}
+
+///
+/// Start of the lexicographically minimal rotation of s, the smallest one if there are
+/// several, O(n)
+///
+pub fn min_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    let at = |i: usize| &s[i % n];
+    let (mut i, mut ans) = (0, 0);
+    while i < n {
+        ans = i;
+        let (mut j, mut k) = (i + 1, i);
+        while j < 2 * n && at(k) <= at(j) {
+            if at(k) < at(j) {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            i += j - k;
+        }
+    }
+    ans
+}
+
+///
+/// Maximal repetition s[start..end] with smallest period `period`, end - start >= 2 period,
+/// that can not be extended to either side keeping the period
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+    pub period: usize,
+}
+
+///
+/// All runs of s ordered by (start, end, period), O(n log n)
+///
+/// Every run has a Lyndon root with respect to one of the two orders of the alphabet, which
+/// is the longest Lyndon word starting at its position, so it's enough to extend these
+/// words to both sides by LCP queries
+///
+pub fn runs<T: Ord>(s: &[T]) -> Vec<Run> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut sorted: Vec<&T> = s.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    let rank: Vec<usize> = s.iter().map(|x| sorted.binary_search(&x).unwrap()).collect();
+    let rev: Vec<usize> = rank.iter().rev().copied().collect();
+    let forward = SuffixArray::new(&rank, sorted.len());
+    let backward = SuffixArray::new(&rev, sorted.len());
+    // longest common suffix of s[..i] and s[..j]
+    let lcs = |i: usize, j: usize| {
+        if i == 0 || j == 0 {
+            0
+        } else {
+            backward.lcp(n - i, n - j)
+        }
+    };
+    let mut res = Vec::new();
+    for inverse in [false, true] {
+        // suffix i is smaller than suffix j > i in the order, a proper prefix is the smallest
+        let less = |i: usize, j: usize| {
+            let k = forward.lcp(i, j);
+            if j + k == n {
+                return false;
+            }
+            let ord = rank[i + k].cmp(&rank[j + k]);
+            (ord == Ordering::Less) ^ inverse
+        };
+        let mut end = vec![n; n];
+        for i in (0..n).rev() {
+            let mut j = i + 1;
+            while j < n && less(i, j) {
+                j = end[j];
+            }
+            end[i] = j;
+            let period = j - i;
+            let l = i - lcs(i, j);
+            let r = if j < n { j + forward.lcp(i, j) } else { j };
+            if r - l >= 2 * period {
+                res.push(Run {
+                    start: l,
+                    end: r,
+                    period,
+                });
+            }
+        }
+    }
+    res.sort();
+    res.dedup();
+    res
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::{lyndon::*, rand::Rng};
+
+    fn random_string(rng: &mut Rng, n: usize, alphabet: u64) -> Vec<u32> {
+        (0..n).map(|_| rng.limit_u64(alphabet) as u32).collect()
+    }
+
+    fn is_lyndon(s: &[u32]) -> bool {
+        (1..s.len()).all(|i| s < &s[i..] && *s < [&s[i..], &s[..i]].concat()[..])
+    }
+
+    fn smallest_period(s: &[u32]) -> usize {
+        (1..=s.len()).find(|&p| (p..s.len()).all(|i| s[i] == s[i - p])).unwrap()
+    }
+
+    #[test]
+    fn test_lyndon_factorization() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 30);
+            let s = random_string(&mut rng, n, 3);
+            let factors = lyndon_factorization(&s);
+            let mut pos = 0;
+            for (i, &(start, len)) in factors.iter().enumerate() {
+                assert_eq!(pos, start);
+                assert!(is_lyndon(&s[start..start + len]));
+                if i > 0 {
+                    let (prev, prev_len) = factors[i - 1];
+                    assert!(s[prev..prev + prev_len] >= s[start..start + len]);
+                }
+                pos += len;
+            }
+            assert_eq!(n, pos);
+            if n > 0 {
+                let rotations: Vec<Vec<u32>> = (0..n).map(|i| [&s[i..], &s[..i]].concat()).collect();
+                let best = (0..n).min_by_key(|&i| &rotations[i]).unwrap();
+                assert_eq!(best, min_rotation(&s));
+            }
+        }
+        assert_eq!(vec![(0, 2), (2, 2), (4, 1)], lyndon_factorization(b"ababa"));
+    }
+
+    #[test]
+    fn test_runs() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 40);
+            let alphabet = rng.range_u64(1, 3);
+            let s = random_string(&mut rng, n, alphabet);
+            let mut expect = Vec::new();
+            for start in 0..n {
+                for end in start + 1..=n {
+                    let period = smallest_period(&s[start..end]);
+                    let left = start == 0 || s[start - 1] != s[start - 1 + period];
+                    let right = end == n || s[end] != s[end - period];
+                    if end - start >= 2 * period && left && right {
+                        expect.push(Run { start, end, period });
+                    }
+                }
+            }
+            expect.sort();
+            assert_eq!(expect, runs(&s));
+        }
+    }
+}
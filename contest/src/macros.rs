@@ -61,14 +61,107 @@ macro_rules! debug_discard {
 }
 
 
+///
+/// Read variables from FastInput
+///
+/// # Example
+///
+/// ```not_run
+/// input! {
+///     fi,
+///     n: usize,
+///     m: usize,
+///     a: [i64; n],
+///     grid: [Bytes; n],
+///     q: [(Usize1, i64); m],
+///     s: Chars,
+///     g: Graph[n, m],
+///     x,
+/// }
+/// ```
+///
+/// - `[T; n]` and `[[T; m]; n]` read vectors
+/// - `(A, B)` reads a tuple
+/// - `Usize1` reads a 1-indexed usize and converts it to 0-indexed
+/// - `Bytes` and `Chars` read a token into `Vec<u8>` and `Vec<char>`
+/// - `DiGraph[n, m]` reads m 1-indexed edges `u v` into `Vec<Vec<SimpleDiEdge>>` with n
+///   vertices, `Graph[n, m]` adds the reversed edge as well
+/// - a variable without type is inferred from its usage
+/// - every variable is bound mutably, unused `mut` is not reported
+///
 macro_rules! input {
+    ($fi: ident $(,)?) => {
+    };
+
+    ($fi: ident, $var: ident : DiGraph[$n: expr, $m: expr] $(, $($arg: tt)*)?) => {
+        #[allow(unused_mut)]
+        let mut $var = $crate::macros::read_value!($fi, @graph $n, $m, false);
+        input!($fi $(, $($arg)*)?)
+    };
+
+    ($fi: ident, $var: ident : Graph[$n: expr, $m: expr] $(, $($arg: tt)*)?) => {
+        #[allow(unused_mut)]
+        let mut $var = $crate::macros::read_value!($fi, @graph $n, $m, true);
+        input!($fi $(, $($arg)*)?)
+    };
+
+    ($fi: ident, $var: ident : $t: tt $(, $($arg: tt)*)?) => {
+        #[allow(unused_mut)]
+        let mut $var = $crate::macros::read_value!($fi, $t);
+        input!($fi $(, $($arg)*)?)
+    };
 
-    ($fi:ident, $var:ident $( : $t:ty)?, $($arg:tt)*) => {
-        let mut $var $(: $t)? = $fi.read();
-        input!($fi, $($arg)*)
+    ($fi: ident, $var: ident : $t: ty $(, $($arg: tt)*)?) => {
+        #[allow(unused_mut)]
+        let mut $var: $t = $fi.read();
+        input!($fi $(, $($arg)*)?)
     };
-    
-    ($fi: ident $(,)?) => {  
+
+    ($fi: ident, $var: ident $(, $($arg: tt)*)?) => {
+        #[allow(unused_mut)]
+        let mut $var = $fi.read();
+        input!($fi $(, $($arg)*)?)
+    };
+}
+
+macro_rules! read_value {
+    ($fi: ident, @graph $n: expr, $m: expr, $bidirectional: expr) => {
+        {
+            let mut g: Vec<Vec<$crate::graph::SimpleDiEdge>> = vec![Vec::new(); $n];
+            for _ in 0..$m {
+                let u = $fi.read::<usize>() - 1;
+                let v = $fi.read::<usize>() - 1;
+                g[u].push($crate::graph::SimpleDiEdge::new(v));
+                if $bidirectional {
+                    g[v].push($crate::graph::SimpleDiEdge::new(u));
+                }
+            }
+            g
+        }
+    };
+
+    ($fi: ident, [$t: tt; $n: expr]) => {
+        (0..$n).map(|_| $crate::macros::read_value!($fi, $t)).collect::<Vec<_>>()
+    };
+
+    ($fi: ident, ($($t: tt),* $(,)?)) => {
+        ($($crate::macros::read_value!($fi, $t)),*)
+    };
+
+    ($fi: ident, Usize1) => {
+        $fi.read::<usize>() - 1
+    };
+
+    ($fi: ident, Bytes) => {
+        $fi.token().to_vec()
+    };
+
+    ($fi: ident, Chars) => {
+        $fi.read::<String>().chars().collect::<Vec<char>>()
+    };
+
+    ($fi: ident, $t: ty) => {
+        $fi.read::<$t>()
     };
 }
 
@@ -157,6 +250,8 @@ macro_rules! SwapAttribute {
 
 pub (crate)use input;
 
+pub (crate)use read_value;
+
 pub (crate)use debug_discard;
 
 pub (crate)use debug;
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::{fast_input::new_fastinput_from_string, macros::input};
+
+    #[test]
+    fn test_input() {
+        let mut fi = new_fastinput_from_string(
+            "3 2\n1 -2 3\n#.\n.#\n2 5\n1 7\nabc\nxyz 9\n".to_string(),
+        );
+        input! {
+            fi,
+            n: usize,
+            m: usize,
+            a: [i64; n],
+            grid: [Bytes; 2],
+            q: [(Usize1, i32); m],
+            s: Chars,
+            t: String,
+            x,
+        }
+        assert_eq!(vec![1, -2, 3], a);
+        assert_eq!(vec![b"#.".to_vec(), b".#".to_vec()], grid);
+        assert_eq!(vec![(1, 5), (0, 7)], q);
+        assert_eq!(vec!['a', 'b', 'c'], s);
+        assert_eq!("xyz", t);
+        assert_eq!(9u64, x);
+    }
+
+    #[test]
+    fn test_input_matrix_and_graph() {
+        let mut fi = new_fastinput_from_string("2 3\n1 2 3\n4 5 6\n1 2\n2 3\n1 2\n3 1".to_string());
+        input! {
+            fi,
+            n: usize,
+            m: usize,
+            mat: [[u32; m]; n],
+            g: Graph[3, 2],
+            dg: DiGraph[3, 2]
+        }
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], mat);
+        let to = |g: &Vec<Vec<crate::graph::SimpleDiEdge>>| -> Vec<Vec<usize>> {
+            g.iter().map(|row| row.iter().map(|e| e.to).collect()).collect()
+        };
+        assert_eq!(vec![vec![1], vec![0, 2], vec![1]], to(&g));
+        assert_eq!(vec![vec![1], vec![], vec![0]], to(&dg));
+    }
+}
@@ -1,13 +1,13 @@
-use std::{io::BufWriter, thread};
+use std::thread;
 
-use contest::{solver::solve_multi, stress::stress};
-use contest::fast_input::FastInput;
+use contest::{samples::run_samples, solver::solve_multi, stress::stress};
+use contest::{fast_input::FastInput, fast_output::FastOutput};
 
 unsafe fn run_in_current_thread() {
     let stdin = std::io::stdin();
     let stdout = std::io::stdout();
     let mut fi = FastInput::new(stdin.lock());
-    let mut fo = BufWriter::new(stdout.lock());
+    let mut fo = FastOutput::new(stdout.lock());
     solve_multi(&mut fi, &mut fo);
 }
 
@@ -19,7 +19,7 @@ unsafe fn run_in_new_thread() {
     .unwrap()
     .join();
 }
-#[cfg(not(feature = "stress"))]
+#[cfg(not(any(feature = "stress", feature = "samples")))]
 fn main() {
     unsafe {
         run_in_current_thread();
@@ -32,3 +32,11 @@ fn main() {
         stress();
     }
 }
+
+///
+/// stress takes precedence when both features are enabled
+///
+#[cfg(all(feature = "samples", not(feature = "stress")))]
+fn main() {
+    run_samples();
+}
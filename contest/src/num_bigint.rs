@@ -0,0 +1,845 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Not, Rem, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+    str::FromStr,
+};
+
+use crate::{
+    math,
+    num_concrete::Concrete,
+    num_integer::Integer,
+    num_number::{FromNumber, Number},
+};
+
+const KARATSUBA_THRESHOLD: usize = 32;
+///
+/// 10^19, the largest power of ten in a limb
+///
+const DECIMAL_BASE: u64 = 10_000_000_000_000_000_000;
+const DECIMAL_DIGITS: usize = 19;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit,
+    ///
+    /// the value doesn't fit in the width of the type
+    ///
+    Overflow,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseBigIntError::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+fn trim(a: &[u64]) -> &[u64] {
+    let len = a.iter().rposition(|&x| x != 0).map_or(0, |i| i + 1);
+    &a[..len]
+}
+
+///
+/// acc += x << (64 * offset), the carry out of acc is dropped
+///
+fn add_at(acc: &mut [u64], x: &[u64], offset: usize) {
+    let mut carry = 0;
+    for (i, slot) in acc.iter_mut().enumerate().skip(offset) {
+        if i - offset >= x.len() && carry == 0 {
+            break;
+        }
+        let s = *slot as u128 + x.get(i - offset).copied().unwrap_or(0) as u128 + carry;
+        *slot = s as u64;
+        carry = s >> 64;
+    }
+}
+
+///
+/// acc -= x, acc must not be less than x
+///
+fn sub_at(acc: &mut [u64], x: &[u64]) {
+    let mut borrow = false;
+    for (i, slot) in acc.iter_mut().enumerate() {
+        if i >= x.len() && !borrow {
+            break;
+        }
+        let (s, b1) = slot.overflowing_sub(x.get(i).copied().unwrap_or(0));
+        let (s, b2) = s.overflowing_sub(borrow as u64);
+        *slot = s;
+        borrow = b1 || b2;
+    }
+}
+
+fn mul_schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let s = x as u128 * y as u128 + res[i + j] as u128 + carry;
+            res[i + j] = s as u64;
+            carry = s >> 64;
+        }
+        res[i + b.len()] = carry as u64;
+    }
+    res
+}
+
+///
+/// Full product with a.len() + b.len() limbs, Karatsuba for long operands
+///
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (ta, tb) = (trim(a), trim(b));
+    let mut res = vec![0; a.len() + b.len()];
+    if ta.len().min(tb.len()) < KARATSUBA_THRESHOLD {
+        let prod = mul_schoolbook(ta, tb);
+        res[..prod.len()].copy_from_slice(&prod);
+        return res;
+    }
+    // a = a1 * B^m + a0, b = b1 * B^m + b0
+    let m = ta.len().max(tb.len()) / 2;
+    let (a0, a1) = ta.split_at(m.min(ta.len()));
+    let (b0, b1) = tb.split_at(m.min(tb.len()));
+    let z0 = mul_limbs(a0, b0);
+    let z2 = mul_limbs(a1, b1);
+    let mut sa = vec![0; a0.len().max(a1.len()) + 1];
+    add_at(&mut sa, a0, 0);
+    add_at(&mut sa, a1, 0);
+    let mut sb = vec![0; b0.len().max(b1.len()) + 1];
+    add_at(&mut sb, b0, 0);
+    add_at(&mut sb, b1, 0);
+    let mut z1 = mul_limbs(&sa, &sb);
+    sub_at(&mut z1, &z0);
+    sub_at(&mut z1, &z2);
+    add_at(&mut res, trim(&z0), 0);
+    add_at(&mut res, trim(&z1), m);
+    add_at(&mut res, trim(&z2), 2 * m);
+    res
+}
+
+///
+/// a /= d in place, return the remainder
+///
+fn div_small(a: &mut [u64], d: u64) -> u64 {
+    let mut rem = 0u128;
+    for x in a.iter_mut().rev() {
+        let cur = rem << 64 | *x as u128;
+        *x = (cur / d as u128) as u64;
+        rem = cur % d as u128;
+    }
+    rem as u64
+}
+
+///
+/// (a / b, a % b) by Knuth's algorithm D, each with the length of a, b must be non-zero
+///
+fn divmod_limbs(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let b = trim(b);
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    let mut quo = a.to_vec();
+    if b.len() == 1 {
+        let mut rem = vec![0; a.len()];
+        rem[0] = div_small(&mut quo, b[0]);
+        return (quo, rem);
+    }
+    let ta = trim(a);
+    let mut rem = vec![0; a.len()];
+    if ta.len() < b.len() {
+        rem[..ta.len()].copy_from_slice(ta);
+        quo.fill(0);
+        return (quo, rem);
+    }
+    let n = b.len();
+    let m = ta.len() - n;
+    let s = b[n - 1].leading_zeros();
+    let normalize = |x: &[u64], len: usize| -> Vec<u64> {
+        let mut res = vec![0; len];
+        for i in 0..x.len() {
+            res[i] |= x[i] << s;
+            if s > 0 {
+                res[i + 1] |= x[i] >> (64 - s);
+            }
+        }
+        res
+    };
+    let bn = normalize(b, n + 1);
+    let mut an = normalize(ta, ta.len() + 1);
+    quo.fill(0);
+    let base = 1u128 << 64;
+    for j in (0..=m).rev() {
+        let num = (an[j + n] as u128) << 64 | an[j + n - 1] as u128;
+        let mut qhat = num / bn[n - 1] as u128;
+        let mut rhat = num % bn[n - 1] as u128;
+        while qhat >= base || qhat * bn[n - 2] as u128 > (rhat << 64 | an[j + n - 2] as u128) {
+            qhat -= 1;
+            rhat += bn[n - 1] as u128;
+            if rhat >= base {
+                break;
+            }
+        }
+        // an[j..=j + n] -= qhat * bn
+        let (mut borrow, mut carry) = (0i128, 0u128);
+        for i in 0..n {
+            let p = qhat * bn[i] as u128 + carry;
+            carry = p >> 64;
+            let t = an[i + j] as i128 - borrow - (p as u64) as i128;
+            an[i + j] = t as u64;
+            borrow = (t < 0) as i128;
+        }
+        let t = an[j + n] as i128 - borrow - carry as i128;
+        an[j + n] = t as u64;
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let s = an[i + j] as u128 + bn[i] as u128 + carry;
+                an[i + j] = s as u64;
+                carry = s >> 64;
+            }
+            an[j + n] = an[j + n].wrapping_add(carry as u64);
+        }
+        quo[j] = qhat as u64;
+    }
+    for i in 0..n {
+        rem[i] = an[i] >> s;
+        if s > 0 {
+            rem[i] |= an[i + 1] << (64 - s);
+        }
+    }
+    (quo, rem)
+}
+
+///
+/// [fill; N] with the last limb set to top and then the first one set to low
+///
+const fn limbs_with<const N: usize>(fill: u64, top: u64, low: u64) -> [u64; N] {
+    let mut res = [fill; N];
+    res[N - 1] = top;
+    res[0] = low;
+    res
+}
+
+fn shl_limbs<const N: usize>(a: &[u64; N], k: usize) -> [u64; N] {
+    let mut res = [0; N];
+    let (q, r) = (k / 64, k % 64);
+    for i in (q..N).rev() {
+        res[i] = a[i - q] << r;
+        if r > 0 && i > q {
+            res[i] |= a[i - q - 1] >> (64 - r);
+        }
+    }
+    res
+}
+
+///
+/// Shift right filling the vacated bits with the bits of fill
+///
+fn shr_limbs<const N: usize>(a: &[u64; N], k: usize, fill: u64) -> [u64; N] {
+    let mut res = [fill; N];
+    let (q, r) = (k / 64, k % 64);
+    for i in 0..N.saturating_sub(q) {
+        res[i] = a[i + q] >> r;
+        if r > 0 {
+            res[i] |= a.get(i + q + 1).copied().unwrap_or(fill) << (64 - r);
+        }
+    }
+    res
+}
+
+macro_rules! BigIntegerImpl {
+    ($name: ident, $signed: expr) => {
+        impl<const N: usize> $name<N> {
+            pub const fn from_limbs(limbs: [u64; N]) -> Self {
+                Self { limbs }
+            }
+
+            ///
+            /// little endian two's complement limbs
+            ///
+            pub fn limbs(&self) -> &[u64; N] {
+                &self.limbs
+            }
+
+            fn is_neg(&self) -> bool {
+                $signed && self.limbs[N - 1] >> 63 == 1
+            }
+
+            ///
+            /// i-th limb of the infinite two's complement expansion
+            ///
+            fn limb(&self, i: usize) -> u64 {
+                match self.limbs.get(i) {
+                    Some(&x) => x,
+                    None if self.is_neg() => !0,
+                    None => 0,
+                }
+            }
+
+            fn low_u128(&self) -> u128 {
+                self.limb(0) as u128 | (self.limb(1) as u128) << 64
+            }
+
+            ///
+            /// |self| as unsigned limbs, MIN maps to itself which is right as unsigned
+            ///
+            fn magnitude(&self) -> [u64; N] {
+                if self.is_neg() {
+                    Self::ZERO.wrapping_sub(*self).limbs
+                } else {
+                    self.limbs
+                }
+            }
+
+            fn with_sign(limbs: &[u64], negative: bool) -> Self {
+                let mut res = Self::ZERO;
+                res.limbs.copy_from_slice(&limbs[..N]);
+                if negative {
+                    res = Self::ZERO.wrapping_sub(res);
+                }
+                res
+            }
+
+            fn from_i128(x: i128) -> Self {
+                let mut res = Self::from_limbs([if x < 0 { !0 } else { 0 }; N]);
+                for i in 0..N.min(2) {
+                    res.limbs[i] = (x >> (64 * i)) as u64;
+                }
+                res
+            }
+
+            fn from_u128(x: u128) -> Self {
+                let mut res = Self::ZERO;
+                for i in 0..N.min(2) {
+                    res.limbs[i] = (x >> (64 * i)) as u64;
+                }
+                res
+            }
+
+            fn from_number<T: Number>(num: T) -> Self {
+                let v = num.as_i128();
+                if <T as FromNumber>::from(v) == num {
+                    return Self::from_i128(v);
+                }
+                let u = num.as_u128();
+                if <T as FromNumber>::from(u) == num {
+                    return Self::from_u128(u);
+                }
+                // wider than 128 bits or not an integer, go through the decimal representation
+                let text = num.to_string();
+                Self::from_str(text.split('.').next().unwrap()).unwrap_or(Self::from_i128(v))
+            }
+
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                let mut res = self;
+                add_at(&mut res.limbs, &rhs.limbs, 0);
+                res
+            }
+
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                self.wrapping_add((!rhs).wrapping_add(Self::ONE))
+            }
+
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                let prod = mul_limbs(&self.limbs, &rhs.limbs);
+                let mut res = Self::ZERO;
+                res.limbs.copy_from_slice(&prod[..N]);
+                res
+            }
+
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let res = self.wrapping_add(rhs);
+                let overflow = if $signed {
+                    self.is_neg() == rhs.is_neg() && res.is_neg() != self.is_neg()
+                } else {
+                    res < self
+                };
+                (res, overflow)
+            }
+
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let res = self.wrapping_sub(rhs);
+                let overflow = if $signed {
+                    self.is_neg() != rhs.is_neg() && res.is_neg() != self.is_neg()
+                } else {
+                    self < rhs
+                };
+                (res, overflow)
+            }
+
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let prod = mul_limbs(&self.magnitude(), &rhs.magnitude());
+                let high = !trim(&prod[N..]).is_empty();
+                let overflow = if $signed {
+                    let mag = BigUint::<N>::with_sign(&prod, false);
+                    let bound = BigUint::<N>::ONE << BigUint::from_u128(64 * N as u128 - 1);
+                    let negative = self.is_neg() != rhs.is_neg() && mag != BigUint::ZERO;
+                    high || mag > bound || (mag == bound && !negative)
+                } else {
+                    high
+                };
+                (self.wrapping_mul(rhs), overflow)
+            }
+
+            ///
+            /// (self / rhs, self % rhs) rounding toward zero like the primitive types
+            ///
+            pub fn divmod(self, rhs: Self) -> (Self, Self) {
+                let (q, r) = divmod_limbs(&self.magnitude(), &rhs.magnitude());
+                (
+                    Self::with_sign(&q, self.is_neg() != rhs.is_neg()),
+                    Self::with_sign(&r, self.is_neg()),
+                )
+            }
+
+            fn count_ones(&self) -> u32 {
+                self.limbs.iter().map(|x| x.count_ones()).sum()
+            }
+
+            fn leading_zeros(&self) -> u32 {
+                match self.limbs.iter().rposition(|&x| x != 0) {
+                    Some(i) => (N - 1 - i) as u32 * 64 + self.limbs[i].leading_zeros(),
+                    None => 64 * N as u32,
+                }
+            }
+
+            fn trailing_zeros(&self) -> u32 {
+                match self.limbs.iter().position(|&x| x != 0) {
+                    Some(i) => i as u32 * 64 + self.limbs[i].trailing_zeros(),
+                    None => 64 * N as u32,
+                }
+            }
+
+            fn shift_amount(rhs: Self) -> usize {
+                if trim(&rhs.limbs[1..]).is_empty() && rhs.limbs[0] < 64 * N as u64 {
+                    rhs.limbs[0] as usize
+                } else {
+                    64 * N
+                }
+            }
+        }
+
+        impl<const N: usize> PartialOrd for $name<N> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const N: usize> Ord for $name<N> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                other
+                    .is_neg()
+                    .cmp(&self.is_neg())
+                    .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+            }
+        }
+
+        impl<const N: usize> Add for $name<N> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                let (res, overflow) = self.overflowing_add(rhs);
+                assert!(!overflow, "attempt to add with overflow");
+                res
+            }
+        }
+
+        impl<const N: usize> Sub for $name<N> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                let (res, overflow) = self.overflowing_sub(rhs);
+                assert!(!overflow, "attempt to subtract with overflow");
+                res
+            }
+        }
+
+        impl<const N: usize> Mul for $name<N> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                let (res, overflow) = self.overflowing_mul(rhs);
+                assert!(!overflow, "attempt to multiply with overflow");
+                res
+            }
+        }
+
+        impl<const N: usize> Div for $name<N> {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                let overflow = $signed && self == Self::MIN && rhs == Self::ZERO.wrapping_sub(Self::ONE);
+                assert!(!overflow, "attempt to divide with overflow");
+                self.divmod(rhs).0
+            }
+        }
+
+        impl<const N: usize> Rem for $name<N> {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                self.divmod(rhs).1
+            }
+        }
+
+        impl<const N: usize> Not for $name<N> {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self::from_limbs(self.limbs.map(|x| !x))
+            }
+        }
+
+        impl<const N: usize> BitAnd for $name<N> {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self::from_limbs(std::array::from_fn(|i| self.limbs[i] & rhs.limbs[i]))
+            }
+        }
+
+        impl<const N: usize> BitOr for $name<N> {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self::from_limbs(std::array::from_fn(|i| self.limbs[i] | rhs.limbs[i]))
+            }
+        }
+
+        impl<const N: usize> BitXor for $name<N> {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self::from_limbs(std::array::from_fn(|i| self.limbs[i] ^ rhs.limbs[i]))
+            }
+        }
+
+        ///
+        /// Shifting by BITS or more clears every bit instead of panicking
+        ///
+        impl<const N: usize> Shl for $name<N> {
+            type Output = Self;
+
+            fn shl(self, rhs: Self) -> Self::Output {
+                Self::from_limbs(shl_limbs(&self.limbs, Self::shift_amount(rhs)))
+            }
+        }
+
+        ///
+        /// Arithmetic shift for signed numbers, shifting by BITS or more leaves only the sign
+        ///
+        impl<const N: usize> Shr for $name<N> {
+            type Output = Self;
+
+            fn shr(self, rhs: Self) -> Self::Output {
+                let fill = if self.is_neg() { !0 } else { 0 };
+                Self::from_limbs(shr_limbs(&self.limbs, Self::shift_amount(rhs), fill))
+            }
+        }
+
+        impl<const N: usize> AddAssign for $name<N> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const N: usize> SubAssign for $name<N> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const N: usize> MulAssign for $name<N> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const N: usize> DivAssign for $name<N> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const N: usize> ShlAssign for $name<N> {
+            fn shl_assign(&mut self, rhs: Self) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<const N: usize> ShrAssign for $name<N> {
+            fn shr_assign(&mut self, rhs: Self) {
+                *self = *self >> rhs;
+            }
+        }
+
+        impl<const N: usize> Display for $name<N> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut mag = self.magnitude();
+                let mut chunks = Vec::new();
+                loop {
+                    chunks.push(div_small(&mut mag, DECIMAL_BASE));
+                    if trim(&mag).is_empty() {
+                        break;
+                    }
+                }
+                let mut res = chunks.pop().unwrap().to_string();
+                for x in chunks.iter().rev() {
+                    res.push_str(&format!("{:0width$}", x, width = DECIMAL_DIGITS));
+                }
+                f.pad_integral(!self.is_neg(), "", &res)
+            }
+        }
+
+        impl<const N: usize> Debug for $name<N> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(self, f)
+            }
+        }
+
+        ///
+        /// Decimal with an optional sign, Err if the value doesn't fit
+        ///
+        impl<const N: usize> FromStr for $name<N> {
+            type Err = ParseBigIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (negative, digits) = match s.strip_prefix('-') {
+                    Some(rest) => (true, rest),
+                    None => (false, s.strip_prefix('+').unwrap_or(s)),
+                };
+                if digits.is_empty() {
+                    return Err(ParseBigIntError::Empty);
+                }
+                if !digits.bytes().all(|c| c.is_ascii_digit()) || (negative && !$signed) {
+                    return Err(ParseBigIntError::InvalidDigit);
+                }
+                let mut mag = BigUint::<N>::ZERO;
+                let head = digits.len() % DECIMAL_DIGITS;
+                let mut start = 0;
+                for end in (head..=digits.len()).step_by(DECIMAL_DIGITS) {
+                    if end == 0 {
+                        continue;
+                    }
+                    let chunk: u64 = digits[start..end].parse().unwrap();
+                    let scale = 10u64.pow((end - start) as u32);
+                    let (x, o1) = mag.overflowing_mul(BigUint::from_u128(scale as u128));
+                    let (x, o2) = x.overflowing_add(BigUint::from_u128(chunk as u128));
+                    if o1 || o2 {
+                        return Err(ParseBigIntError::Overflow);
+                    }
+                    mag = x;
+                    start = end;
+                }
+                let res = Self::with_sign(&mag.limbs, negative);
+                // the only magnitude with the sign bit set that fits is the one of MIN
+                if res.is_neg() != (negative && mag != BigUint::ZERO) {
+                    return Err(ParseBigIntError::Overflow);
+                }
+                Ok(res)
+            }
+        }
+
+        ///
+        /// Exact for every integer type and for other big integers, floats are truncated
+        ///
+        impl<const N: usize> FromNumber for $name<N> {
+            fn from(num: impl Number) -> Self {
+                Self::from_number(num)
+            }
+        }
+
+        impl<const N: usize> Number for $name<N> {
+            const MAX: Self = Self::from_limbs(if $signed {
+                limbs_with(!0, i64::MAX as u64, if N == 1 { i64::MAX as u64 } else { !0 })
+            } else {
+                [!0; N]
+            });
+            const MIN: Self = Self::from_limbs(if $signed {
+                limbs_with(0, 1 << 63, if N == 1 { 1 << 63 } else { 0 })
+            } else {
+                [0; N]
+            });
+            const ZERO: Self = Self::from_limbs([0; N]);
+            const ONE: Self = Self::from_limbs(limbs_with(0, 0, 1));
+
+            type HighPrecisionType = Self;
+            type SignedType = BigInt<N>;
+
+            fn upgrade(&self) -> Self::HighPrecisionType {
+                *self
+            }
+            fn as_signed(&self) -> Self::SignedType {
+                BigInt::from_limbs(self.limbs)
+            }
+            fn as_i8(&self) -> i8 {
+                self.low_u128() as i8
+            }
+            fn as_u8(&self) -> u8 {
+                self.low_u128() as u8
+            }
+            fn as_i16(&self) -> i16 {
+                self.low_u128() as i16
+            }
+            fn as_u16(&self) -> u16 {
+                self.low_u128() as u16
+            }
+            fn as_i32(&self) -> i32 {
+                self.low_u128() as i32
+            }
+            fn as_u32(&self) -> u32 {
+                self.low_u128() as u32
+            }
+            fn as_i64(&self) -> i64 {
+                self.low_u128() as i64
+            }
+            fn as_u64(&self) -> u64 {
+                self.low_u128() as u64
+            }
+            fn as_i128(&self) -> i128 {
+                self.low_u128() as i128
+            }
+            fn as_u128(&self) -> u128 {
+                self.low_u128()
+            }
+            fn as_isize(&self) -> isize {
+                self.low_u128() as isize
+            }
+            fn as_usize(&self) -> usize {
+                self.low_u128() as usize
+            }
+            fn as_f32(&self) -> f32 {
+                self.as_f64() as f32
+            }
+            fn as_f64(&self) -> f64 {
+                let mag = self
+                    .magnitude()
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, &x| acc * 18446744073709551616.0 + x as f64);
+                if self.is_neg() {
+                    -mag
+                } else {
+                    mag
+                }
+            }
+        }
+
+        impl<const N: usize> Concrete for $name<N> {}
+
+        impl<const N: usize> Integer for $name<N> {
+            type UnsignedIntegerType = BigUint<N>;
+            type SignedIntegerType = BigInt<N>;
+            type HighPrecisionIntegerType = Self;
+            const BITS: i32 = 64 * N as i32;
+
+            fn as_high_precision_integer_type(&self) -> Self::HighPrecisionIntegerType {
+                *self
+            }
+            fn as_unsigned(&self) -> Self::UnsignedIntegerType {
+                BigUint::from_limbs(self.limbs)
+            }
+            fn bit_count(&self) -> Self {
+                Self::from_u128(self.count_ones() as u128)
+            }
+            fn higest_set_bit_offset(&self) -> i32 {
+                Self::BITS - 1 - self.leading_zeros() as i32
+            }
+            fn lowest_set_bit(&self) -> Self {
+                *self & Self::ZERO.wrapping_sub(*self)
+            }
+            fn higest_one_bit(&self) -> Self {
+                if *self == Self::ZERO {
+                    Self::ZERO
+                } else {
+                    Self::ONE << Self::from_u128(self.higest_set_bit_offset() as u128)
+                }
+            }
+            fn count_leading_zero(&self) -> i32 {
+                self.leading_zeros() as i32
+            }
+            fn count_trailing_zero(&self) -> i32 {
+                self.trailing_zeros() as i32
+            }
+            fn bit_signed_right_shift(&self, step: i32) -> Self {
+                let fill = if self.limbs[N - 1] >> 63 == 1 { !0 } else { 0 };
+                Self::from_limbs(shr_limbs(&self.limbs, (step.max(0) as usize).min(64 * N), fill))
+            }
+            fn bit_unsigned_right_shift(&self, step: i32) -> Self {
+                Self::from_limbs(shr_limbs(&self.limbs, (step.max(0) as usize).min(64 * N), 0))
+            }
+            ///
+            /// a * b mod m in [0, m) for m > 0 without overflow
+            ///
+            fn mul_mod(a: Self, b: Self, m: Self) -> Self {
+                let prod = mul_limbs(&a.magnitude(), &b.magnitude());
+                let (_, r) = divmod_limbs(&prod, &m.limbs);
+                let r = Self::with_sign(&r, false);
+                if a.is_neg() != b.is_neg() && r != Self::ZERO {
+                    m - r
+                } else {
+                    r
+                }
+            }
+            fn pow(a: Self, n: u32) -> Self {
+                math::pow(a, n)
+            }
+            fn add_overflow(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_add(b)
+            }
+            fn mul_overflow(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_mul(b)
+            }
+            fn div_and_remainder(a: Self, b: Self) -> (Self, Self) {
+                a.divmod(b)
+            }
+        }
+    };
+}
+
+///
+/// Fixed width unsigned integer of 64 * N bits, `+`, `-`, `*` and `/` panic on overflow
+/// in release builds as well, so a result is either exact or the program stops,
+/// `wrapping_*` and `overflowing_*` are there when wrapping is intended
+///
+/// The width is fixed since `Number` requires `Copy` and constant `MAX`/`MIN`, which a
+/// growable limb vector can't provide, so pick N large enough for the problem. For the
+/// same reason `upgrade` returns self instead of something wider
+///
+/// # Example
+///
+/// ```ignore
+/// let x: U256 = "340282366920938463463374607431768211456".parse().unwrap();
+/// assert_eq!(x * x, math::pow(x, 2u32));
+/// assert_eq!("115792089237316195423570985008687907853269984665640564039457584007913129639935", U256::MAX.to_string());
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigUint<const N: usize> {
+    limbs: [u64; N],
+}
+
+///
+/// Fixed width two's complement signed integer of 64 * N bits, see `BigUint`
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigInt<const N: usize> {
+    limbs: [u64; N],
+}
+
+BigIntegerImpl!(BigUint, false);
+BigIntegerImpl!(BigInt, true);
+
+pub type U256 = BigUint<4>;
+pub type I256 = BigInt<4>;
+pub type U512 = BigUint<8>;
+pub type I512 = BigInt<8>;
+pub type U1024 = BigUint<16>;
+pub type I1024 = BigInt<16>;
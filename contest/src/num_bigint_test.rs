@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        math,
+        num_bigint::*,
+        num_gcd::gcd,
+        num_integer::Integer,
+        num_number::{FromNumber, Number},
+        rand::Rng,
+    };
+
+    type I128 = BigInt<2>;
+    type U128 = BigUint<2>;
+
+    fn random_i128(rng: &mut Rng) -> i128 {
+        let bits = rng.range_u64(0, 127);
+        ((rng.u64() as u128) << 64 | rng.u64() as u128) as i128 >> bits
+    }
+
+    fn random_big<const N: usize>(rng: &mut Rng, len: usize) -> BigUint<N> {
+        let mut limbs = [0; N];
+        for x in limbs.iter_mut().take(len) {
+            *x = rng.u64();
+        }
+        BigUint::from_limbs(limbs)
+    }
+
+    #[test]
+    fn test_same_as_i128() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..10000 {
+            let (a, b) = (random_i128(&mut rng), random_i128(&mut rng));
+            let (x, y): (I128, I128) = (FromNumber::from(a), FromNumber::from(b));
+            assert_eq!(a, x.as_i128());
+            assert_eq!(a.wrapping_add(b), x.wrapping_add(y).as_i128());
+            assert_eq!(a.wrapping_sub(b), x.wrapping_sub(y).as_i128());
+            assert_eq!(a.wrapping_mul(b), x.wrapping_mul(y).as_i128());
+            if let Some(c) = a.checked_add(b) {
+                assert_eq!(c, (x + y).as_i128());
+            }
+            if let Some(c) = a.checked_mul(b) {
+                assert_eq!(c, (x * y).as_i128());
+            }
+            assert_eq!(a.overflowing_sub(b), {
+                let (v, o) = x.overflowing_sub(y);
+                (v.as_i128(), o)
+            });
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            assert_eq!(a & b, (x & y).as_i128());
+            assert_eq!(a ^ !b, (x ^ !y).as_i128());
+            assert_eq!(a.to_string(), x.to_string());
+            assert_eq!(x, a.to_string().parse().unwrap());
+            assert_eq!(a.overflowing_mul(b), {
+                let (v, o) = I128::mul_overflow(x, y);
+                (v.as_i128(), o)
+            });
+            assert_eq!(a.overflowing_add(b), {
+                let (v, o) = I128::add_overflow(x, y);
+                (v.as_i128(), o)
+            });
+            if b != 0 && !(a == i128::MIN && b == -1) {
+                assert_eq!(a / b, (x / y).as_i128());
+                assert_eq!(a % b, (x % y).as_i128());
+            }
+            let k = rng.range_u64(0, 127) as u32;
+            assert_eq!(a << k, (x << FromNumber::from(k)).as_i128());
+            assert_eq!(a >> k, (x >> FromNumber::from(k)).as_i128());
+            assert_eq!(a.count_leading_zero(), x.count_leading_zero());
+            assert_eq!(a.count_trailing_zero(), x.count_trailing_zero());
+            assert_eq!(a.bit_count(), x.bit_count().as_i128());
+            assert_eq!(a.higest_one_bit(), x.higest_one_bit().as_i128());
+        }
+    }
+
+    #[test]
+    fn test_same_as_u128() {
+        let mut rng = Rng::new_with_seed(1);
+        for _ in 0..10000 {
+            let (a, b) = (random_i128(&mut rng) as u128, random_i128(&mut rng) as u128);
+            let (x, y): (U128, U128) = (FromNumber::from(a), FromNumber::from(b));
+            assert_eq!(a.wrapping_sub(b), x.wrapping_sub(y).as_u128());
+            assert_eq!(a.wrapping_mul(b), x.wrapping_mul(y).as_u128());
+            assert_eq!(a.overflowing_sub(b).1, x.overflowing_sub(y).1);
+            assert_eq!(a.overflowing_add(b).1, x.overflowing_add(y).1);
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            assert_eq!(a.to_string(), x.to_string());
+            assert_eq!(x, a.to_string().parse().unwrap());
+            assert_eq!(a.overflowing_mul(b).1, U128::mul_overflow(x, y).1);
+            if b != 0 {
+                assert_eq!((a / b, a % b), {
+                    let (q, r) = x.divmod(y);
+                    (q.as_u128(), r.as_u128())
+                });
+            }
+            let k = rng.range_u64(0, 127) as u32;
+            assert_eq!(a >> k, (x >> FromNumber::from(k)).as_u128());
+            let (c, d, m) = (a as i64, b as i64, (b >> 64) as i64 & i64::MAX | 1);
+            let mm: U128 = FromNumber::from(m);
+            assert_eq!(i64::mul_mod(c, d, m), I128::mul_mod(FromNumber::from(c), FromNumber::from(d), FromNumber::from(m)).as_i64());
+            assert_eq!(u64::mul_mod(c as u64, d as u64, m as u64), U128::mul_mod(FromNumber::from(c as u64), FromNumber::from(d as u64), mm).as_u64());
+        }
+    }
+
+    #[test]
+    fn test_constants_and_format() {
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            U256::MAX.to_string()
+        );
+        assert_eq!(
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            I256::MIN.to_string()
+        );
+        assert_eq!(I256::MAX, I256::MIN.wrapping_sub(I256::ONE));
+        assert_eq!(i64::MAX.to_string(), BigInt::<1>::MAX.to_string());
+        assert_eq!(i64::MIN.to_string(), BigInt::<1>::MIN.to_string());
+        assert_eq!("0", U256::ZERO.to_string());
+        assert_eq!("-1", (I256::ZERO - I256::ONE).to_string());
+        assert_eq!("  -42", format!("{:>5}", "-42".parse::<I256>().unwrap()));
+        assert_eq!(Err(ParseBigIntError::Empty), "".parse::<U256>());
+        assert_eq!(Err(ParseBigIntError::InvalidDigit), "-1".parse::<U256>());
+        assert_eq!(Err(ParseBigIntError::InvalidDigit), "12a".parse::<I256>());
+        assert_eq!(Ok(U256::ZERO), "-0".parse::<I256>().map(|x| x.as_unsigned()));
+        // one past the bounds doesn't wrap
+        let (max, min) = (U256::MAX.to_string(), I256::MIN.to_string());
+        assert_eq!(Ok(U256::MAX), max.parse());
+        assert_eq!(Ok(I256::MIN), min.parse());
+        assert_eq!(Err(ParseBigIntError::Overflow), "115792089237316195423570985008687907853269984665640564039457584007913129639936".parse::<U256>());
+        assert_eq!(Err(ParseBigIntError::Overflow), "-57896044618658097711785492504343953926634992332820282019728792003956564819969".parse::<I256>());
+        assert_eq!(Err(ParseBigIntError::Overflow), max.parse::<I256>());
+        assert_eq!(Err(ParseBigIntError::Overflow), format!("{}0", max).parse::<U256>());
+        assert_eq!(I256::ONE, "+1".parse().unwrap());
+        assert_eq!(1e30, <U256 as FromNumber>::from(1e30).as_f64());
+        assert_eq!(-12, <I256 as FromNumber>::from(-12.7).as_i64());
+        // wider than 128 bits goes through decimal
+        let big = "123456789012345678901234567890123456789012345678901234567890".parse::<U512>().unwrap();
+        assert_eq!(big.to_string(), <U1024 as FromNumber>::from(big).to_string());
+    }
+
+    #[test]
+    fn test_factorial_and_generic_algorithms() {
+        let mut fact = U512::ONE;
+        for i in 1..=50u32 {
+            fact *= FromNumber::from(i);
+        }
+        assert_eq!(
+            "30414093201713378043612608166064768844377641568960512000000000000",
+            fact.to_string()
+        );
+        let two: U512 = FromNumber::from(2);
+        assert_eq!(U512::ONE << FromNumber::from(300), math::pow(two, 300u32));
+        assert_eq!(U512::ONE << FromNumber::from(300), U512::pow(two, 300));
+        let a = math::pow(FromNumber::from(6), 100u32);
+        let b = math::pow(FromNumber::from(15), 80u32);
+        let g: U512 = math::pow(FromNumber::from(3), 80u32);
+        assert_eq!(g, gcd(a, b));
+        let m: I256 = "1000000000000000000000000000057".parse().unwrap();
+        let x: I256 = "-999999999999999999999999999999".parse().unwrap();
+        let r = I256::mul_mod(x, x, m);
+        assert_eq!(r, Integer::modular((x % m) * (x % m), m));
+    }
+
+    #[test]
+    fn test_long_division_and_karatsuba() {
+        let mut rng = Rng::new_with_seed(2);
+        for _ in 0..200 {
+            let (la, lb) = (rng.range_usize(1, 40), rng.range_usize(1, 40));
+            let a: BigUint<80> = random_big(&mut rng, la);
+            let b: BigUint<80> = random_big(&mut rng, lb);
+            let c: BigUint<80> = random_big(&mut rng, 39);
+            if b == BigUint::ZERO {
+                continue;
+            }
+            let d: BigUint<80> = random_big(&mut rng, 40);
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(d * c / c, d);
+            assert_eq!((d + a) * (c + b), d * c + d * b + a * c + a * b);
+            assert_eq!(a * b / b, a);
+            assert_eq!(a * b % b, BigUint::ZERO);
+            let (q, r) = a.divmod(b);
+            assert!(r < b);
+            assert_eq!(a, q * b + r);
+            assert_eq!(a, a.to_string().parse().unwrap());
+        }
+        // quotient digit estimates that need the add back step
+        let b = BigUint::<4>::from_limbs([0, 0, 1 << 63, 0]);
+        let a = BigUint::<4>::from_limbs([!0, !0, (1 << 63) - 1, 1 << 62]);
+        let (q, r) = a.divmod(b);
+        assert_eq!(a, q * b + r);
+        assert!(r < b);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_overflow_panics() {
+        let _ = U256::MAX + U256::ONE;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_overflow_panics() {
+        let x = I256::ONE << FromNumber::from(128);
+        let _ = x * x;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_overflow_panics() {
+        let _ = U256::ZERO - U256::ONE;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_overflow_panics() {
+        let _ = I256::MIN / (I256::ZERO - I256::ONE);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    arithmetic::{AssociativeAdd, AssociativeMul, CommutativeAdd, CommutativeMul, IdentityAdd, IdentityMul, MulInv},
+    macros::should,
+    num_float::float,
+    num_gcd::gcd,
+    num_integer::Integer,
+    num_number::{FromNumber, Number},
+};
+
+///
+/// Exact rational number num / den, always reduced with den > 0
+///
+/// Sums and products are reduced by gcd before multiplying, but the result still has to
+/// fit in T and so does the cross product when comparing, since `upgrade` of i128 and of
+/// big integers doesn't widen. Use `BigInt<N>` with N large enough when the numbers grow
+/// beyond i64, it panics on overflow instead of wrapping silently
+///
+/// # Example
+///
+/// ```ignore
+/// let a = Fraction::new(1i64, 6);
+/// let b = Fraction::new(-3i64, 4);
+/// assert_eq!(Fraction::new(-7, 12), a + b);
+/// assert_eq!(-1, b.floor());
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction<T: Integer> {
+    num: T,
+    den: T,
+}
+
+impl<T: Integer> Fraction<T> {
+    pub fn new(num: T, den: T) -> Self {
+        should!(den != T::ZERO);
+        let g = gcd(num.absolute(), den.absolute());
+        let (num, den) = (num / g, den / g);
+        if den.is_negative() {
+            Self { num: num.negative(), den: den.negative() }
+        } else {
+            Self { num, den }
+        }
+    }
+
+    pub fn with_integer(x: T) -> Self {
+        Self { num: x, den: T::ONE }
+    }
+
+    pub fn num(&self) -> T {
+        self.num
+    }
+
+    pub fn den(&self) -> T {
+        self.den
+    }
+
+    ///
+    /// Largest integer not greater than self
+    ///
+    pub fn floor(&self) -> T {
+        let q = self.num / self.den;
+        if q * self.den > self.num {
+            q - T::ONE
+        } else {
+            q
+        }
+    }
+
+    ///
+    /// Smallest integer not less than self
+    ///
+    pub fn ceil(&self) -> T {
+        let q = self.num / self.den;
+        if q * self.den < self.num {
+            q + T::ONE
+        } else {
+            q
+        }
+    }
+
+    pub fn to_float(&self) -> float {
+        (self.num.as_f64() / self.den.as_f64()).into()
+    }
+}
+
+impl<T: Integer> Add for Fraction<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let g = gcd(self.den, rhs.den);
+        Self::new(
+            self.num * (rhs.den / g) + rhs.num * (self.den / g),
+            self.den / g * rhs.den,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Fraction<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let g = gcd(self.den, rhs.den);
+        Self::new(
+            self.num * (rhs.den / g) - rhs.num * (self.den / g),
+            self.den / g * rhs.den,
+        )
+    }
+}
+
+impl<T: Integer> Mul for Fraction<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let g1 = gcd(self.num.absolute(), rhs.den);
+        let g2 = gcd(rhs.num.absolute(), self.den);
+        Self::new(
+            (self.num / g1) * (rhs.num / g2),
+            (self.den / g2) * (rhs.den / g1),
+        )
+    }
+}
+
+impl<T: Integer> Div for Fraction<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.possible_inv().unwrap())
+    }
+}
+
+impl<T: Integer> PartialOrd for Fraction<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Ord for Fraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.num.upgrade() * other.den.upgrade();
+        let rhs = other.num.upgrade() * self.den.upgrade();
+        lhs.partial_cmp(&rhs).unwrap()
+    }
+}
+
+impl<T: Integer> Display for Fraction<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == T::ONE {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl<T: Integer> FromNumber for Fraction<T> {
+    fn from(num: impl Number) -> Self {
+        Self::with_integer(FromNumber::from(num))
+    }
+}
+
+impl<T: Integer> AssociativeAdd for Fraction<T> {}
+impl<T: Integer> CommutativeAdd for Fraction<T> {}
+impl<T: Integer> AssociativeMul for Fraction<T> {}
+impl<T: Integer> CommutativeMul for Fraction<T> {}
+impl<T: Integer> IdentityAdd for Fraction<T> {
+    fn zero() -> Self {
+        Self::with_integer(T::ZERO)
+    }
+}
+impl<T: Integer> IdentityMul for Fraction<T> {
+    fn one() -> Self {
+        Self::with_integer(T::ONE)
+    }
+}
+impl<T: Integer> MulInv for Fraction<T> {
+    fn possible_inv(&self) -> Option<Self> {
+        if self.num == T::ZERO {
+            None
+        } else {
+            Some(Self::new(self.den, self.num))
+        }
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        arithmetic::MulInv,
+        num_bigint::I256,
+        num_fraction::*,
+        num_number::{FromNumber, Number},
+        poly_interpolation::GravityLargrangeInterpolation,
+        rand::Rng,
+        vector_matrix::Matrix,
+    };
+
+    type F = Fraction<i64>;
+
+    fn frac(a: i64, b: i64) -> F {
+        Fraction::new(a, b)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..10000 {
+            let (a, b) = (rng.range_i64(-1000, 1000), rng.range_i64(1, 1000) * if rng.bool() { 1 } else { -1 });
+            let (c, d) = (rng.range_i64(-1000, 1000), rng.range_i64(1, 1000));
+            let (x, y) = (frac(a, b), frac(c, d));
+            assert!(x.den() > 0);
+            assert_eq!(1, crate::num_gcd::gcd(x.num().abs(), x.den()));
+            assert_eq!(x, frac(a * 7, b * 7));
+            assert_eq!(frac(a * d + c * b, b * d), x + y);
+            assert_eq!(frac(a * d - c * b, b * d), x - y);
+            assert_eq!(frac(a * c, b * d), x * y);
+            if c != 0 {
+                assert_eq!(frac(a * d, b * c), x / y);
+            }
+            assert_eq!((a as i128 * d as i128 * b.signum() as i128).cmp(&(c as i128 * b.abs() as i128)), x.cmp(&y));
+            let v = a as f64 / b as f64;
+            assert_eq!(v.floor() as i64, x.floor());
+            assert_eq!(v.ceil() as i64, x.ceil());
+            assert!((v - x.to_float().as_f64()).abs() < 1e-12);
+        }
+        assert_eq!(None, frac(0, 5).possible_inv());
+        assert_eq!(Some(frac(-5, 3)), frac(3, -5).possible_inv());
+        assert_eq!("-3/5", frac(3, -5).to_string());
+        assert_eq!("2", frac(4, 2).to_string());
+        assert_eq!(frac(3, 1), FromNumber::from(3));
+    }
+
+    #[test]
+    fn test_hilbert_matrix() {
+        // the Hilbert matrix is notoriously ill-conditioned in floating point
+        let n = 6;
+        let h = |i: usize, j: usize| Fraction::new(I256::ONE, FromNumber::from(i + j + 1));
+        let data: Vec<Fraction<I256>> = (0..n * n).map(|k| h(k / n, k % n)).collect();
+        let m = Matrix::with_initial_value(data, n, n);
+        // det H_6 = 1 / 186313420339200000
+        let det = m.clone().determinant();
+        assert_eq!("1/186313420339200000", det.to_string());
+        let inv = m.possible_inv().unwrap();
+        let prod = m.clone() * inv.clone();
+        for i in 0..n {
+            for j in 0..n {
+                let expected = if i == j { Fraction::with_integer(I256::ONE) } else { Fraction::with_integer(I256::ZERO) };
+                assert_eq!(expected, prod[(i, j)]);
+                // the inverse of a Hilbert matrix has integer entries
+                assert_eq!(I256::ONE, inv[(i, j)].den());
+            }
+        }
+        let singular = Matrix::with_initial_value(vec![frac(1, 2), frac(1, 3), frac(3, 2), frac(1, 1)], 2, 2);
+        assert_eq!(frac(0, 1), singular.clone().determinant());
+        assert!(singular.possible_inv().is_none());
+    }
+
+    #[test]
+    fn test_interpolation() {
+        // p(x) = x^3 / 6 - 2x / 3 + 1 / 2
+        let coef = [frac(1, 2), frac(-2, 3), frac(0, 1), frac(1, 6)];
+        let p = |x: F| coef.iter().rev().fold(frac(0, 1), |acc, &c| acc * x + c);
+        let mut interp = GravityLargrangeInterpolation::new(4);
+        for x in [frac(-1, 2), frac(0, 1), frac(3, 7), frac(5, 1)] {
+            interp.add(x, p(x));
+        }
+        assert_eq!(coef.to_vec(), interp.interpolate());
+        assert_eq!(p(frac(11, 3)), interp.estimate_point(frac(11, 3)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::cell::UnsafeCell;
+
 use crate::{num_number::{Number, FromNumber}, macros::should};
 
 
@@ -47,17 +49,14 @@ fn xorshift128p(state: &mut xorshift128p_state) -> u64 {
     t + s
 }
 
+///
+/// Per thread random generator, so that stress rounds running in parallel don't share state
+///
 pub fn rng() -> &'static mut Rng {
-    static mut singleton: Option<Rng> = None;
-    unsafe {
-        match &mut singleton {
-            Some(x) => x,
-            None => {
-                singleton = Some(Rng::new());
-                rng()
-            }
-        }
+    thread_local! {
+        static singleton: UnsafeCell<Rng> = UnsafeCell::new(Rng::new());
     }
+    singleton.with(|x| unsafe { &mut *x.get() })
 }
 
 pub struct Rng {
@@ -116,6 +115,14 @@ impl Rng {
     pub fn range_usize(&mut self, l: usize, r: usize) -> usize {
         self.range_u64(l as u64, r as u64) as usize
     }
+    #[inline]
+    pub fn range_i64(&mut self, l: i64, r: i64) -> i64 {
+        l.wrapping_add(self.limit_u64(r.wrapping_sub(l) as u64 + 1) as i64)
+    }
+    #[inline]
+    pub fn bool(&mut self) -> bool {
+        self.u64() & 1 == 1
+    }
 }
 
 pub fn random<T: Number>(n: T) -> T {
@@ -0,0 +1,177 @@
+use std::{
+    fs,
+    io::{BufReader, Cursor},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    fast_input::FastInput,
+    fast_output::FastOutput,
+    solver::solve_multi,
+    stress::panic_message,
+    stress_external_member::against,
+};
+
+const STACK_SIZE: usize = 256 << 20;
+///
+/// lines of the line by line diff shown for a failing sample
+///
+const DIFF_LINES: usize = 20;
+
+pub struct Sample {
+    pub name: String,
+    pub input: PathBuf,
+    ///
+    /// None if there is no `.out` file next to the `.in` file
+    ///
+    pub output: Option<PathBuf>,
+}
+
+pub struct SampleReport {
+    pub actual: String,
+    pub elapsed: Duration,
+    ///
+    /// None if there is no expected output to compare with
+    ///
+    pub verdict: Option<Result<(), String>>,
+}
+
+///
+/// `*.in` files under dir with their `*.out` files, ordered by name with numbers compared
+/// by value so that 2.in goes before 10.in
+///
+pub fn find_samples(dir: &Path) -> Vec<Sample> {
+    let mut res: Vec<Sample> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.extension().map(|x| x == "in").unwrap_or(false))
+                .map(|input| {
+                    let output = input.with_extension("out");
+                    Sample {
+                        name: input.file_stem().unwrap().to_string_lossy().to_string(),
+                        output: if output.exists() { Some(output) } else { None },
+                        input,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    res.sort_by(|a, b| (a.name.len(), &a.name).cmp(&(b.name.len(), &b.name)));
+    res
+}
+
+///
+/// Run solve_multi on a thread with large stack, Err with the panic message if it panics
+///
+pub fn run_solver(input: &str) -> (Result<String, String>, Duration) {
+    let input = input.to_string();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let mut output = Vec::new();
+            let start = Instant::now();
+            let res = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+                let mut fi = FastInput::new(BufReader::new(Cursor::new(&input)));
+                solve_multi(&mut fi, &mut FastOutput::new(&mut output));
+            }));
+            let elapsed = start.elapsed();
+            let res = res
+                .map(|_| String::from_utf8_lossy(&output).to_string())
+                .map_err(panic_message);
+            (res, elapsed)
+        })
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+pub fn run_sample(input: &str, expect: Option<&str>) -> SampleReport {
+    let (res, elapsed) = run_solver(input);
+    match res {
+        Ok(actual) => SampleReport {
+            verdict: expect.map(|expect| against(input, expect, &actual)),
+            actual,
+            elapsed,
+        },
+        Err(msg) => SampleReport {
+            actual: String::new(),
+            elapsed,
+            verdict: Some(Err(format!("Runtime error: {}", msg))),
+        },
+    }
+}
+
+///
+/// Differing lines marked by `-` for expect and `+` for actual
+///
+pub fn line_diff(expect: &str, actual: &str) -> String {
+    let expect: Vec<&str> = expect.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut res = String::new();
+    let mut shown = 0;
+    for i in 0..expect.len().max(actual.len()) {
+        let (e, a) = (expect.get(i), actual.get(i));
+        if e.map(|x| x.trim_end()) == a.map(|x| x.trim_end()) {
+            continue;
+        }
+        if shown == DIFF_LINES {
+            res.push_str("...\n");
+            break;
+        }
+        shown += 1;
+        if let Some(e) = e {
+            res.push_str(&format!("{:>4} - {}\n", i + 1, e));
+        }
+        if let Some(a) = a {
+            res.push_str(&format!("{:>4} + {}\n", i + 1, a));
+        }
+    }
+    res
+}
+
+///
+/// Run every sample under `tests/samples` of the crate, SAMPLES_DIR overrides the directory
+///
+pub fn run_samples() {
+    let dir = std::env::var("SAMPLES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("samples"));
+    let samples = find_samples(&dir);
+    if samples.is_empty() {
+        println!("No sample found in {}", dir.display());
+        return;
+    }
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failed = Vec::new();
+    for sample in &samples {
+        let input = fs::read_to_string(&sample.input).unwrap();
+        let expect = sample.output.as_ref().map(|p| fs::read_to_string(p).unwrap());
+        let report = run_sample(&input, expect.as_deref());
+        match &report.verdict {
+            Some(Ok(())) => println!("Sample {} passed in {:?}", sample.name, report.elapsed),
+            Some(Err(msg)) => {
+                failed.push(sample.name.clone());
+                println!("Sample {} failed in {:?}: {}", sample.name, report.elapsed, msg);
+                if let Some(expect) = &expect {
+                    print!("{}", line_diff(expect, &report.actual));
+                }
+            }
+            None => {
+                println!("Sample {} finished in {:?} without expected output:", sample.name, report.elapsed);
+                print!("{}", report.actual);
+            }
+        }
+    }
+    panic::set_hook(hook);
+    if failed.is_empty() {
+        println!("All {} samples passed", samples.len());
+    } else {
+        println!("{} of {} samples failed: {}", failed.len(), samples.len(), failed.join(", "));
+        panic!("Fail!");
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::samples::*;
+
+    #[test]
+    fn test_line_diff() {
+        assert_eq!("", line_diff("1\n2 \n", "1\n2\n"));
+        assert_eq!("   2 - 2\n   2 + 3\n   3 - 4\n", line_diff("1\n2\n4", "1\n3"));
+    }
+
+    #[test]
+    fn test_find_samples() {
+        let dir = std::env::temp_dir().join(format!("samples_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["10.in", "10.out", "2.in", "a.in", "a.out", "note.txt"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let samples = find_samples(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let names: Vec<&str> = samples.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(vec!["2", "a", "10"], names);
+        assert!(samples[0].output.is_none());
+        assert!(samples[2].output.is_some());
+    }
+}
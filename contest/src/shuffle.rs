@@ -1,8 +1,14 @@
-use crate::{rand::rng, collection::swap_element};
+use crate::{rand::{rng, Rng}, collection::swap_element};
 
 pub fn shuffle<T>(data: &mut [T]) {
-    let rng = rng();
-    for i in (0..data.len()).into_iter().rev() {
+    shuffle_with(rng(), data);
+}
+
+///
+/// Fisher-Yates shuffle drawing from the given generator, for reproducible orders
+///
+pub fn shuffle_with<T>(rng: &mut Rng, data: &mut [T]) {
+    for i in (0..data.len()).rev() {
         let swap_pos = rng.limit_usize(i + 1);
         swap_element(data, i, swap_pos);
     }
@@ -1,8 +1,29 @@
-use std::{io::{Write, BufReader, Cursor}, fmt::Display};
+use std::{
+    any::Any,
+    fmt::Display,
+    io::{BufReader, Cursor, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::{fast_input::FastInput, rand::{Rng, rng}};
+use crate::{checker::Checker, fast_input::FastInput, fast_output::FastOutput, interactive::{interact, InteractConfig, Interactor, PipeReader, PipeWriter}, rand::{Rng, rng}};
 
-use crate::{solver::solve_multi, stress_external_member::{generate_test, against, brute_force}};
+use crate::{solver::solve_multi, stress_external_member::{generate_test, against, brute_force, interactor}};
+
+const STACK_SIZE: usize = 256 << 20;
+///
+/// seeds tried for each size while shrinking
+///
+const SHRINK_SEEDS: u64 = 200;
+///
+/// inputs judged while dropping tokens
+///
+const SHRINK_BUDGET: usize = 2000;
 
 fn printable_input(s: String) -> String {
     let len = 1000;
@@ -13,34 +34,438 @@ fn printable_input(s: String) -> String {
     }
 }
 
+///
+/// # Environment
+///
+/// Default values can be overridden by STRESS_THREADS, STRESS_ROUNDS, STRESS_SECONDS,
+/// STRESS_SEED and STRESS_SIZE, and STRESS_REPLAY=seed replays a single round.
+///
+#[derive(Clone)]
+pub struct StressConfig {
+    pub threads: usize,
+    pub rounds: usize,
+    ///
+    /// stop once the time budget is used up, even if not all rounds finished
+    ///
+    pub time_limit: Option<Duration>,
+    ///
+    /// round i uses seed + i for generating input and for `rng()`
+    ///
+    pub seed: u64,
+    ///
+    /// size passed to generate_test, shrinking tries smaller sizes
+    ///
+    pub size: usize,
+    pub shrink: bool,
+    ///
+    /// time limit of each input tried while dropping tokens
+    ///
+    pub shrink_timeout: Duration,
+    ///
+    /// compare output of solve_multi with brute_force, `against` by default
+    ///
+    pub checker: Arc<dyn Checker>,
+    ///
+    /// judge solve_multi by talking with interactor instead of comparing with brute_force,
+    /// `stress_external_member::interactor` by default
+    ///
+    pub interactor: Option<Arc<dyn Interactor>>,
+    pub interact: InteractConfig,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().map(|x| x.get()).unwrap_or(1),
+            rounds: 10000,
+            time_limit: None,
+            seed: 0,
+            size: 100,
+            shrink: true,
+            shrink_timeout: Duration::from_secs(1),
+            checker: Arc::new(against),
+            interactor: interactor(),
+            interact: InteractConfig::default(),
+        }
+    }
+}
+
+fn env<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|x| x.parse().ok())
+}
+
+impl StressConfig {
+    pub fn from_env() -> Self {
+        let mut res = Self::default();
+        if let Some(x) = env("STRESS_THREADS") {
+            res.threads = x;
+        }
+        if let Some(x) = env("STRESS_ROUNDS") {
+            res.rounds = x;
+        }
+        if let Some(x) = env::<f64>("STRESS_SECONDS") {
+            res.time_limit = Some(Duration::from_secs_f64(x));
+        }
+        if let Some(x) = env("STRESS_SEED") {
+            res.seed = x;
+        }
+        if let Some(x) = env("STRESS_SIZE") {
+            res.size = x;
+        }
+        res
+    }
+}
+
+pub enum Verdict {
+    Accept,
+    WrongAnswer { expect: String, actual: String, message: String },
+    RuntimeError(String),
+    ///
+    /// brute force or interactor panics, the input is invalid
+    ///
+    Invalid(String),
+    InteractFailed { message: String, transcript: String },
+}
+
+impl Verdict {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::WrongAnswer { .. } | Verdict::RuntimeError(_) | Verdict::InteractFailed { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Accept => write!(f, "Accept"),
+            Verdict::WrongAnswer { expect, actual, message } => {
+                write!(f, "Wrong answer: {}\n\nExpect:\n{}\n\nActual:\n{}", message, expect, actual)
+            }
+            Verdict::RuntimeError(msg) => write!(f, "Runtime error: {}", msg),
+            Verdict::Invalid(msg) => write!(f, "Invalid input: {}", msg),
+            Verdict::InteractFailed { message, transcript } => {
+                write!(f, "Interaction failed: {}\n\nTranscript:\n{}", message, transcript)
+            }
+        }
+    }
+}
+
+pub fn panic_message(e: Box<dyn Any + Send>) -> String {
+    if let Some(s) = e.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = e.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut input = OutputWrapper::new();
+    generate_test(&mut Rng::new_with_seed(seed), size, &mut input);
+    input.to_string()
+}
+
+///
+/// Run brute_force and solve_multi against input, `rng()` is initialized with seed before each
+///
+/// solve_multi talks with the interactor instead if there is one
+///
+pub unsafe fn judge(input: &str, seed: u64, config: &StressConfig) -> Verdict {
+    if let Some(interactor) = &config.interactor {
+        let solve = move |fi: &mut FastInput<PipeReader>, fo: &mut PipeWriter| {
+            rng().init(seed);
+            solve_multi(fi, &mut FastOutput::new(fo))
+        };
+        return match interact(input, solve, interactor.clone(), config.interact) {
+            Ok(()) => Verdict::Accept,
+            Err(e) if e.invalid => Verdict::Invalid(e.message),
+            Err(e) => Verdict::InteractFailed {
+                message: e.message,
+                transcript: printable_input(e.transcript),
+            },
+        };
+    }
+    let run = |f: &dyn Fn(&mut OutputWrapper)| -> Result<String, String> {
+        rng().init(seed);
+        let mut output = OutputWrapper::new();
+        panic::catch_unwind(AssertUnwindSafe(|| f(&mut output)))
+            .map(|_| output.to_string())
+            .map_err(panic_message)
+    };
+    let expect = match run(&|fo| brute_force(&mut FastInput::new(BufReader::new(Cursor::new(input))), fo)) {
+        Ok(x) => x,
+        Err(msg) => return Verdict::Invalid(format!("brute force panicked: {}", msg)),
+    };
+    let actual = match run(&|fo| solve_multi(&mut FastInput::new(BufReader::new(Cursor::new(input))), &mut FastOutput::new(fo))) {
+        Ok(x) => x,
+        Err(msg) => return Verdict::RuntimeError(msg),
+    };
+    match config.checker.check(input, &expect, &actual) {
+        Ok(()) => Verdict::Accept,
+        Err(message) => Verdict::WrongAnswer { expect, actual, message },
+    }
+}
+
+///
+/// judge threads started by judge_with_timeout that haven't finished yet
+///
+static RUNNING_JUDGES: AtomicUsize = AtomicUsize::new(0);
+///
+/// shrinking gives up once this many timed out judges are still running, they can't be
+/// killed and each keeps a core busy until it finishes or the process exits
+///
+const MAX_ABANDONED: usize = 4;
+
+///
+/// None if judge doesn't finish in time, the running thread is abandoned in that case and
+/// counted in RUNNING_JUDGES until it finishes
+///
+fn judge_with_timeout(input: &str, seed: u64, config: &StressConfig) -> Option<Verdict> {
+    let (tx, rx) = mpsc::channel();
+    let input = input.to_string();
+    let timeout = config.shrink_timeout;
+    let config = config.clone();
+    RUNNING_JUDGES.fetch_add(1, Ordering::SeqCst);
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let verdict = unsafe { judge(&input, seed, &config) };
+            RUNNING_JUDGES.fetch_sub(1, Ordering::SeqCst);
+            tx.send(verdict).ok();
+        })
+        .unwrap();
+    rx.recv_timeout(timeout).ok()
+}
+
+///
+/// Some(true) if input still fails or doesn't finish in time, None once too many timed out
+/// judges are left running
+///
+fn still_fails(input: &str, seed: u64, config: &StressConfig) -> Option<bool> {
+    if RUNNING_JUDGES.load(Ordering::SeqCst) >= MAX_ABANDONED {
+        return None;
+    }
+    Some(judge_with_timeout(input, seed, config).map(|v| v.is_fail()).unwrap_or(true))
+}
+
+fn join_tokens(lines: &[Vec<&str>]) -> String {
+    let mut res = String::new();
+    for line in lines {
+        res.push_str(&line.join(" "));
+        res.push('\n');
+    }
+    res
+}
+
+///
+/// Halve size as long as one of SHRINK_SEEDS inputs generated with that size is shorter
+/// and still fails, return seed, size and input of the last failing one
+///
+/// fails returns None to stop shrinking
+///
+pub fn shrink_by_size(
+    seed: u64,
+    size: usize,
+    input: String,
+    generator: impl Fn(u64, usize) -> String,
+    mut fails: impl FnMut(&str, u64) -> Option<bool>,
+) -> (u64, usize, String) {
+    let (mut best_seed, mut best_size, mut best) = (seed, size, input);
+    let mut size = size;
+    'shrink: while size > 1 {
+        size /= 2;
+        let mut found = false;
+        for s in (0..SHRINK_SEEDS).map(|k| seed.wrapping_add(k << 32)) {
+            let candidate = generator(s, size);
+            if candidate.len() >= best.len() {
+                continue;
+            }
+            match fails(&candidate, s) {
+                Some(true) => {
+                    (best_seed, best_size, best) = (s, size, candidate);
+                    found = true;
+                    break;
+                }
+                Some(false) => {}
+                None => break 'shrink,
+            }
+        }
+        if !found {
+            break;
+        }
+    }
+    (best_seed, best_size, best)
+}
+
+///
+/// Remove lines and then tokens one by one from the end, as long as input keeps failing,
+/// at most budget inputs are tried
+///
+/// fails returns None to stop shrinking
+///
+pub fn drop_tokens(input: &str, budget: usize, mut fails: impl FnMut(&str) -> Option<bool>) -> String {
+    let mut lines: Vec<Vec<&str>> = input
+        .lines()
+        .map(|l| l.split_ascii_whitespace().collect())
+        .filter(|l: &Vec<&str>| !l.is_empty())
+        .collect();
+    let mut budget = budget;
+    let mut changed = true;
+    'shrink: while changed {
+        changed = false;
+        let mut i = lines.len();
+        while i > 0 {
+            i -= 1;
+            if budget == 0 {
+                break 'shrink;
+            }
+            budget -= 1;
+            let mut candidate = lines.clone();
+            candidate.remove(i);
+            match fails(&join_tokens(&candidate)) {
+                Some(true) => {
+                    lines = candidate;
+                    changed = true;
+                }
+                Some(false) => {}
+                None => break 'shrink,
+            }
+        }
+        let mut i = lines.len();
+        while i > 0 {
+            i -= 1;
+            let mut j = lines[i].len();
+            while j > 0 {
+                j -= 1;
+                if budget == 0 {
+                    break 'shrink;
+                }
+                budget -= 1;
+                let mut candidate = lines.clone();
+                candidate[i].remove(j);
+                let line_removed = candidate[i].is_empty();
+                if line_removed {
+                    candidate.remove(i);
+                }
+                match fails(&join_tokens(&candidate)) {
+                    Some(true) => {
+                        lines = candidate;
+                        changed = true;
+                        if line_removed {
+                            break;
+                        }
+                    }
+                    Some(false) => {}
+                    None => break 'shrink,
+                }
+            }
+        }
+    }
+    join_tokens(&lines)
+}
+
+///
+/// Find a smaller failing input, first by generating tests with smaller size, then by
+/// dropping tokens
+///
+/// Every input is judged with shrink_timeout and one that doesn't finish in time counts as
+/// failing, so an infinite loop found while shrinking doesn't hang the run
+///
+fn shrink(seed: u64, size: usize, input: String, config: &StressConfig) -> String {
+    let (best_seed, best_size, best) = shrink_by_size(seed, size, input, generate, |candidate, s| {
+        still_fails(candidate, s, config)
+    });
+    println!("Shrink by size: seed = {}, size = {}, {} bytes", best_seed, best_size, best.len());
+    let res = drop_tokens(&best, SHRINK_BUDGET, |candidate| still_fails(candidate, best_seed, config));
+    println!("Shrink by tokens: {} bytes", res.len());
+    if RUNNING_JUDGES.load(Ordering::SeqCst) >= MAX_ABANDONED {
+        println!("Shrinking stopped early, {} timed out judges are still running", MAX_ABANDONED);
+    }
+    res
+}
+
+///
+/// Replay the round generated by seed
+///
+pub unsafe fn replay(seed: u64, config: &StressConfig) {
+    let input = generate(seed, config.size);
+    println!("Input:\n{}", input);
+    println!("{}", judge(&input, seed, config));
+}
+
 pub unsafe fn stress() {
-    let mut round = 0;
-    let mut rng_for_input_generator = Rng::new_with_seed(0);
-    loop {
-        round += 1;
-        if round >= 10000 {
-            println!("Pass stress!");
-            return;
+    match env("STRESS_REPLAY") {
+        Some(seed) => replay(seed, &StressConfig::from_env()),
+        None => stress_with(StressConfig::from_env()),
+    }
+}
+
+///
+/// Run rounds on several threads, report the failing round with the smallest index and
+/// shrink its input
+///
+pub unsafe fn stress_with(config: StressConfig) {
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let passed = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let failure: Mutex<Option<(usize, u64, String)>> = Mutex::new(None);
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    thread::scope(|scope| {
+        for _ in 0..config.threads.max(1) {
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || loop {
+                    let round = next.fetch_add(1, Ordering::SeqCst);
+                    let timeout = config.time_limit.map(|t| start.elapsed() >= t).unwrap_or(false);
+                    if round >= config.rounds || timeout || stop.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    let seed = config.seed.wrapping_add(round as u64);
+                    let input = generate(seed, config.size);
+                    let verdict = judge(&input, seed, &config);
+                    if let Verdict::Accept = verdict {
+                        let done = passed.fetch_add(1, Ordering::SeqCst) + 1;
+                        if done.is_multiple_of(1000) {
+                            println!("{} rounds passed in {:?}", done, start.elapsed());
+                        }
+                        continue;
+                    }
+                    stop.store(true, Ordering::SeqCst);
+                    let mut failure = failure.lock().unwrap();
+                    if failure.as_ref().map(|x| round < x.0).unwrap_or(true) {
+                        *failure = Some((round, seed, input));
+                    }
+                })
+                .unwrap();
         }
-        //init seed for program
-        rng().init(0);
-
-        let mut input = OutputWrapper::new();
-        generate_test(&mut rng_for_input_generator, &mut input);
-        println!("Test {}:", round);
-        println!("{}\n", printable_input(input.to_string()));
-        let mut actual_output = OutputWrapper::new();
-        let mut expect_output = OutputWrapper::new();
-        solve_multi(&mut FastInput::new(BufReader::new(Cursor::new(input.to_string()))), &mut actual_output);
-        brute_force(&mut FastInput::new(BufReader::new(Cursor::new(input.to_string()))), &mut expect_output);
-        if !against(&actual_output.to_string(), &expect_output.to_string()) {
-            println!("Test case {}:", round);
-            println!("\nInput:\n{}", input);
-            println!("\nExpect:\n{}", expect_output);
-            println!("\nActual:\n{}", actual_output);
-            panic!("Fail!");
+    });
+    let failure = failure.into_inner().unwrap();
+    let (round, seed, input) = match failure {
+        None => {
+            panic::set_hook(hook);
+            println!("Pass stress! {} rounds in {:?}", passed.into_inner(), start.elapsed());
+            return;
         }
+        Some(x) => x,
+    };
+    println!("Test case {} failed, replay with STRESS_REPLAY={} STRESS_SIZE={}", round + 1, seed, config.size);
+    println!("\nInput:\n{}", printable_input(input.clone()));
+    println!("\n{}", judge(&input, seed, &config));
+    let input = if config.shrink {
+        shrink(seed, config.size, input, &config)
+    } else {
+        input
+    };
+    let verdict = judge_with_timeout(&input, seed, &config);
+    panic::set_hook(hook);
+    println!("\nMinimal input:\n{}", input);
+    match verdict {
+        Some(verdict) => println!("\n{}", verdict),
+        None => println!("\nTime limit exceeded: judge doesn't finish in {:?}", config.shrink_timeout),
     }
+    panic!("Fail!");
 }
 struct OutputWrapper(Vec<u8>);
 impl OutputWrapper {
@@ -64,4 +489,4 @@ impl Write for OutputWrapper {
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
-}
\ No newline at end of file
+}
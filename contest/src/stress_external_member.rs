@@ -1,6 +1,6 @@
-use std::{io::{BufRead, Write}, mem::swap};
+use std::{io::{BufRead, Write}, mem::swap, sync::Arc};
 
-use crate::{fast_input::FastInput, rand::Rng};
+use crate::{checker::{Checker, TokenChecker}, fast_input::FastInput, interactive::Interactor, rand::Rng};
 
 
 pub unsafe fn brute_force<InT>(fi: &mut FastInput<InT>, fo: &mut impl Write)
@@ -9,12 +9,28 @@ where
  
 }
 
-pub fn generate_test(rng: &mut Rng, fo: &mut impl Write) {
+///
+/// size is an upper bound of the test scale suggested by stress, it gets smaller while
+/// shrinking a failing test
+///
+pub fn generate_test(rng: &mut Rng, size: usize, fo: &mut impl Write) {
 
 }
 
-pub fn against(a: &String, b: &String) -> bool {
-    let da: Vec<_> = a.split_ascii_whitespace().collect();    
-    let db: Vec<_> = b.split_ascii_whitespace().collect();
-    da == db
-}
\ No newline at end of file
+///
+/// Checker used by stress, return Err with a message if actual output is rejected, see
+/// `checker::TokenChecker` for float tolerance and case-insensitive YES/NO
+///
+pub fn against(input: &str, expect: &str, actual: &str) -> Result<(), String> {
+    TokenChecker::exact().check(input, expect, actual)
+}
+
+///
+/// Interactor for interactive problems, it reads the hidden test from input and answers
+/// queries through io, brute_force and against are not used then
+///
+/// Return None for ordinary problems
+///
+pub fn interactor() -> Option<Arc<dyn Interactor>> {
+    None
+}
@@ -0,0 +1,371 @@
+use std::{collections::HashSet, fmt::Display, io::Write};
+
+use crate::{rand::Rng, shuffle::shuffle_with};
+
+///
+/// Shape of the tree produced by `random_tree`
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeShape {
+    ///
+    /// uniform over all labeled trees, decoded from a random Prüfer sequence
+    ///
+    Prufer,
+    ///
+    /// father of i is one of the few vertices just before i, so the tree is deep
+    ///
+    PathLike,
+    ///
+    /// every vertex hangs on one of a few centers
+    ///
+    StarLike,
+    ///
+    /// a path with leaves attached to it
+    ///
+    Caterpillar,
+}
+
+///
+/// Random permutation of 0..n
+///
+pub fn permutation(rng: &mut Rng, n: usize) -> Vec<usize> {
+    let mut data: Vec<usize> = (0..n).collect();
+    shuffle_with(rng, &mut data);
+    data
+}
+
+///
+/// n distinct values in [0, limit) in random order, by Floyd's sampling
+///
+/// # Panic
+///
+/// Panic if n > limit
+///
+pub fn distinct_u64(rng: &mut Rng, n: usize, limit: u64) -> Vec<u64> {
+    assert!(n as u64 <= limit, "can't pick {} distinct values from {}", n, limit);
+    let mut set = HashSet::with_capacity(n);
+    let mut res = Vec::with_capacity(n);
+    for j in limit - n as u64..limit {
+        let t = rng.limit_u64(j + 1);
+        let x = if set.insert(t) { t } else { j };
+        set.insert(x);
+        res.push(x);
+    }
+    shuffle_with(rng, &mut res);
+    res
+}
+
+///
+/// n distinct values in [l, r] in random order
+///
+pub fn distinct_array(rng: &mut Rng, n: usize, l: i64, r: i64) -> Vec<i64> {
+    distinct_u64(rng, n, r.wrapping_sub(l) as u64 + 1)
+        .into_iter()
+        .map(|x| l.wrapping_add(x as i64))
+        .collect()
+}
+
+///
+/// n values in [l, r], duplicates allowed
+///
+pub fn array(rng: &mut Rng, n: usize, l: i64, r: i64) -> Vec<i64> {
+    (0..n).map(|_| rng.range_i64(l, r)).collect()
+}
+
+///
+/// String of length n whose characters are picked from charset
+///
+pub fn string(rng: &mut Rng, n: usize, charset: &[u8]) -> String {
+    (0..n)
+        .map(|_| charset[rng.limit_usize(charset.len())] as char)
+        .collect()
+}
+
+pub fn lowercase(rng: &mut Rng, n: usize, alphabet: usize) -> String {
+    let charset: Vec<u8> = (b'a'..=b'z').take(alphabet).collect();
+    string(rng, n, &charset)
+}
+
+fn prufer_decode(code: &[usize]) -> Vec<(usize, usize)> {
+    let n = code.len() + 2;
+    let mut degree = vec![1; n];
+    for &x in code {
+        degree[x] += 1;
+    }
+    let mut res = Vec::with_capacity(n - 1);
+    let mut ptr = 0;
+    while degree[ptr] != 1 {
+        ptr += 1;
+    }
+    let mut leaf = ptr;
+    for &x in code {
+        res.push((leaf, x));
+        degree[x] -= 1;
+        if degree[x] == 1 && x < ptr {
+            leaf = x;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    res.push((leaf, n - 1));
+    res
+}
+
+///
+/// Relabel vertices randomly, shuffle edges and swap endpoints of some of them, so that
+/// the shape can't be inferred from the order
+///
+fn disguise(rng: &mut Rng, n: usize, edges: &mut [(usize, usize)]) {
+    let p = permutation(rng, n);
+    for e in edges.iter_mut() {
+        *e = (p[e.0], p[e.1]);
+        if rng.bool() {
+            *e = (e.1, e.0);
+        }
+    }
+    shuffle_with(rng, edges);
+}
+
+///
+/// Edges of a random tree with n vertices labeled 0..n
+///
+pub fn random_tree(rng: &mut Rng, n: usize, shape: TreeShape) -> Vec<(usize, usize)> {
+    if n <= 1 {
+        return Vec::new();
+    }
+    let mut edges: Vec<(usize, usize)> = match shape {
+        TreeShape::Prufer => {
+            let code: Vec<usize> = (0..n - 2).map(|_| rng.limit_usize(n)).collect();
+            prufer_decode(&code)
+        }
+        TreeShape::PathLike => {
+            let width = rng.range_usize(1, 3);
+            (1..n)
+                .map(|i| (i - 1 - rng.limit_usize(width.min(i)), i))
+                .collect()
+        }
+        TreeShape::StarLike => {
+            let centers = rng.range_usize(1, 3);
+            (1..n).map(|i| (rng.limit_usize(centers.min(i)), i)).collect()
+        }
+        TreeShape::Caterpillar => {
+            let spine = rng.range_usize(1, n);
+            (1..n)
+                .map(|i| {
+                    if i < spine {
+                        (i - 1, i)
+                    } else {
+                        (rng.limit_usize(spine), i)
+                    }
+                })
+                .collect()
+        }
+    };
+    disguise(rng, n, &mut edges);
+    edges
+}
+
+///
+/// Edges of a random tree with a randomly chosen shape
+///
+pub fn random_tree_any_shape(rng: &mut Rng, n: usize) -> Vec<(usize, usize)> {
+    let shapes = [
+        TreeShape::Prufer,
+        TreeShape::PathLike,
+        TreeShape::StarLike,
+        TreeShape::Caterpillar,
+    ];
+    let shape = shapes[rng.limit_usize(shapes.len())];
+    random_tree(rng, n, shape)
+}
+
+///
+/// k-th pair (i, j) with i < j, pairs ordered by j then i
+///
+fn unordered_pair(k: u64) -> (usize, usize) {
+    let mut j = ((1.0 + (1.0 + 8.0 * k as f64).sqrt()) / 2.0) as u64;
+    while j * (j - 1) / 2 > k {
+        j -= 1;
+    }
+    while (j + 1) * j / 2 <= k {
+        j += 1;
+    }
+    ((k - j * (j - 1) / 2) as usize, j as usize)
+}
+
+fn pair_count(n: usize) -> u64 {
+    n as u64 * (n as u64).saturating_sub(1) / 2
+}
+
+///
+/// m distinct unordered pairs over 0..n without self loops, pairs in avoid are never chosen
+///
+fn distinct_pairs(rng: &mut Rng, n: usize, m: usize, avoid: &HashSet<(usize, usize)>) -> Vec<(usize, usize)> {
+    let total = pair_count(n) - avoid.len() as u64;
+    assert!(m as u64 <= total, "at most {} edges can be added, but {} required", total, m);
+    let mut res = Vec::with_capacity(m);
+    let mut chosen = HashSet::with_capacity(m);
+    if (m as u64) * 2 <= total {
+        while res.len() < m {
+            let (u, v) = (rng.limit_usize(n), rng.limit_usize(n));
+            let e = (u.min(v), u.max(v));
+            if u != v && !avoid.contains(&e) && chosen.insert(e) {
+                res.push(e);
+            }
+        }
+    } else {
+        let mut all: Vec<(usize, usize)> = (0..pair_count(n))
+            .map(unordered_pair)
+            .filter(|e| !avoid.contains(e))
+            .collect();
+        shuffle_with(rng, &mut all);
+        all.truncate(m);
+        res = all;
+    }
+    res
+}
+
+///
+/// Edges of a random simple graph with n vertices and m edges
+///
+/// # Panic
+///
+/// Panic if m > n(n-1)/2
+///
+pub fn random_graph(rng: &mut Rng, n: usize, m: usize) -> Vec<(usize, usize)> {
+    let mut edges = distinct_pairs(rng, n, m, &HashSet::new());
+    disguise(rng, n, &mut edges);
+    edges
+}
+
+///
+/// Edges of a random connected simple graph with n vertices and m edges, a random tree
+/// plus m - n + 1 extra edges
+///
+/// # Panic
+///
+/// Panic unless n - 1 <= m <= n(n-1)/2
+///
+pub fn random_connected_graph(rng: &mut Rng, n: usize, m: usize) -> Vec<(usize, usize)> {
+    assert!(m + 1 >= n, "{} edges can't connect {} vertices", m, n);
+    let mut edges = random_tree_any_shape(rng, n);
+    let tree: HashSet<(usize, usize)> = edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+    edges.extend(distinct_pairs(rng, n, m + 1 - n.max(1), &tree));
+    disguise(rng, n, &mut edges);
+    edges
+}
+
+///
+/// Edges (u, v) of a random DAG with n vertices and m edges, without duplicated edges
+///
+pub fn random_dag(rng: &mut Rng, n: usize, m: usize) -> Vec<(usize, usize)> {
+    let order = permutation(rng, n);
+    let mut edges: Vec<(usize, usize)> = distinct_pairs(rng, n, m, &HashSet::new())
+        .into_iter()
+        .map(|(u, v)| (order[u], order[v]))
+        .collect();
+    shuffle_with(rng, &mut edges);
+    edges
+}
+
+///
+/// Edges (u, v) of a random bipartite graph, u in 0..n1 is on the left and v in 0..n2 on
+/// the right, without duplicated edges
+///
+/// # Panic
+///
+/// Panic if m > n1 * n2
+///
+pub fn random_bipartite_graph(rng: &mut Rng, n1: usize, n2: usize, m: usize) -> Vec<(usize, usize)> {
+    distinct_u64(rng, m, n1 as u64 * n2 as u64)
+        .into_iter()
+        .map(|k| ((k / n2 as u64) as usize, (k % n2 as u64) as usize))
+        .collect()
+}
+
+///
+/// Attach a weight in [l, r] to each edge
+///
+pub fn with_weights(rng: &mut Rng, edges: &[(usize, usize)], l: i64, r: i64) -> Vec<(usize, usize, i64)> {
+    edges
+        .iter()
+        .map(|&(u, v)| (u, v, rng.range_i64(l, r)))
+        .collect()
+}
+
+///
+/// An edge written as one line with 1-based endpoints
+///
+pub trait EdgeLine {
+    fn write_line(&self, fo: &mut impl Write);
+}
+
+impl EdgeLine for (usize, usize) {
+    fn write_line(&self, fo: &mut impl Write) {
+        writeln!(fo, "{} {}", self.0 + 1, self.1 + 1).unwrap();
+    }
+}
+
+impl<W: Display> EdgeLine for (usize, usize, W) {
+    fn write_line(&self, fo: &mut impl Write) {
+        writeln!(fo, "{} {} {}", self.0 + 1, self.1 + 1, self.2).unwrap();
+    }
+}
+
+///
+/// Values separated by space in one line
+///
+pub fn write_line<T: Display>(fo: &mut impl Write, data: &[T]) {
+    let line: Vec<String> = data.iter().map(|x| x.to_string()).collect();
+    writeln!(fo, "{}", line.join(" ")).unwrap();
+}
+
+///
+/// `n` in the first line and the values in the second line
+///
+pub fn write_array<T: Display>(fo: &mut impl Write, data: &[T]) {
+    writeln!(fo, "{}", data.len()).unwrap();
+    write_line(fo, data);
+}
+
+///
+/// Values shifted to 1-based, e.g. a permutation
+///
+pub fn write_one_based(fo: &mut impl Write, data: &[usize]) {
+    let data: Vec<usize> = data.iter().map(|x| x + 1).collect();
+    write_line(fo, &data);
+}
+
+pub fn write_edges<E: EdgeLine>(fo: &mut impl Write, edges: &[E]) {
+    for e in edges {
+        e.write_line(fo);
+    }
+}
+
+///
+/// `n` in the first line followed by n - 1 edges
+///
+pub fn write_tree<E: EdgeLine>(fo: &mut impl Write, n: usize, edges: &[E]) {
+    writeln!(fo, "{}", n).unwrap();
+    write_edges(fo, edges);
+}
+
+///
+/// `n m` in the first line followed by m edges
+///
+pub fn write_graph<E: EdgeLine>(fo: &mut impl Write, n: usize, edges: &[E]) {
+    writeln!(fo, "{} {}", n, edges.len()).unwrap();
+    write_edges(fo, edges);
+}
+
+///
+/// `n1 n2 m` in the first line followed by m edges, both sides are numbered from 1
+///
+pub fn write_bipartite_graph<E: EdgeLine>(fo: &mut impl Write, n1: usize, n2: usize, edges: &[E]) {
+    writeln!(fo, "{} {} {}", n1, n2, edges.len()).unwrap();
+    write_edges(fo, edges);
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        arithmetic::Nil,
+        dsu_compress_path::DSU,
+        fast_input::new_fastinput_from_string,
+        rand::Rng,
+        stress_generator::*,
+    };
+
+    fn connected(n: usize, edges: &[(usize, usize)]) -> bool {
+        let mut dsu: DSU = DSU::new(vec![Nil; n]);
+        for &(u, v) in edges {
+            dsu.union(u, v, Nil);
+        }
+        (0..n).all(|i| dsu.find(i) == dsu.find(0))
+    }
+
+    fn simple(edges: &[(usize, usize)]) -> bool {
+        let set: HashSet<(usize, usize)> = edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        set.len() == edges.len() && edges.iter().all(|&(u, v)| u != v)
+    }
+
+    #[test]
+    fn test_tree() {
+        let mut rng = Rng::new_with_seed(0);
+        for shape in [TreeShape::Prufer, TreeShape::PathLike, TreeShape::StarLike, TreeShape::Caterpillar] {
+            for n in 1..30 {
+                let edges = random_tree(&mut rng, n, shape);
+                assert_eq!(n - 1, edges.len());
+                assert!(connected(n, &edges));
+            }
+        }
+    }
+
+    #[test]
+    fn test_prufer_uniform() {
+        //there are 16 labeled trees with 4 vertices
+        let mut rng = Rng::new_with_seed(0);
+        let mut count = std::collections::HashMap::new();
+        for _ in 0..16000 {
+            let mut edges: Vec<(usize, usize)> = random_tree(&mut rng, 4, TreeShape::Prufer)
+                .into_iter()
+                .map(|(u, v)| (u.min(v), u.max(v)))
+                .collect();
+            edges.sort();
+            *count.entry(edges).or_insert(0) += 1;
+        }
+        assert_eq!(16, count.len());
+        assert!(count.values().all(|&c| c > 800 && c < 1200));
+    }
+
+    #[test]
+    fn test_graph() {
+        let mut rng = Rng::new_with_seed(0);
+        for n in 1..12 {
+            for m in n - 1..=n * (n - 1) / 2 {
+                let edges = random_connected_graph(&mut rng, n, m);
+                assert_eq!(m, edges.len());
+                assert!(simple(&edges));
+                assert!(connected(n, &edges));
+                assert!(simple(&random_graph(&mut rng, n, m)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dag() {
+        let mut rng = Rng::new_with_seed(0);
+        let n = 10;
+        for m in 0..=n * (n - 1) / 2 {
+            let edges = random_dag(&mut rng, n, m);
+            assert!(simple(&edges));
+            let mut indeg = vec![0; n];
+            for &(_, v) in &edges {
+                indeg[v] += 1;
+            }
+            let mut queue: Vec<usize> = (0..n).filter(|&i| indeg[i] == 0).collect();
+            let mut visited = 0;
+            while let Some(u) = queue.pop() {
+                visited += 1;
+                for &(a, b) in &edges {
+                    if a == u {
+                        indeg[b] -= 1;
+                        if indeg[b] == 0 {
+                            queue.push(b);
+                        }
+                    }
+                }
+            }
+            assert_eq!(n, visited);
+        }
+    }
+
+    #[test]
+    fn test_bipartite_and_array() {
+        let mut rng = Rng::new_with_seed(0);
+        let edges = random_bipartite_graph(&mut rng, 3, 4, 12);
+        let set: HashSet<(usize, usize)> = edges.iter().cloned().collect();
+        assert_eq!(12, set.len());
+        assert!(edges.iter().all(|&(u, v)| u < 3 && v < 4));
+        let a = distinct_array(&mut rng, 10, -5, 4);
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!((-5..5).collect::<Vec<i64>>(), sorted);
+        let s = string(&mut rng, 20, b"ab");
+        assert!(s.len() == 20 && s.bytes().all(|c| c == b'a' || c == b'b'));
+    }
+
+    #[test]
+    fn test_write() {
+        let mut rng = Rng::new_with_seed(0);
+        let tree = random_tree_any_shape(&mut rng, 5);
+        let edges = with_weights(&mut rng, &tree, 1, 9);
+        let mut buf = Vec::new();
+        write_tree(&mut buf, 5, &edges);
+        write_array(&mut buf, &[3, 1, 2]);
+        let mut fi = new_fastinput_from_string(String::from_utf8(buf).unwrap());
+        assert_eq!(5, fi.read::<usize>());
+        for &(u, v, w) in &edges {
+            assert_eq!((u + 1, v + 1, w), (fi.read(), fi.read(), fi.read()));
+        }
+        assert_eq!(3, fi.read::<usize>());
+        assert_eq!(vec![3, 1, 2], (0..3).map(|_| fi.read::<i32>()).collect::<Vec<_>>());
+        assert!(fi.eof());
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::{rand::Rng, stress::*};
+
+    ///
+    /// size random numbers in [0, 100) after the count
+    ///
+    fn generator(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new_with_seed(seed);
+        let a: Vec<String> = (0..size).map(|_| rng.range_u64(0, 99).to_string()).collect();
+        format!("{}\n{}\n", size, a.join(" "))
+    }
+
+    ///
+    /// a solution that is wrong once some number is at least 95
+    ///
+    fn fails(input: &str) -> bool {
+        input
+            .split_ascii_whitespace()
+            .skip(1)
+            .any(|x| x.parse::<u64>().unwrap() >= 95)
+    }
+
+    #[test]
+    fn test_shrink() {
+        let (seed, size) = (0..).map(|s| (s, 1000)).find(|&(s, n)| fails(&generator(s, n))).unwrap();
+        let input = generator(seed, size);
+        let (seed, size, best) = shrink_by_size(seed, size, input.clone(), generator, |s, _| Some(fails(s)));
+        assert!(size < 1000);
+        assert!(best.len() < input.len());
+        assert_eq!(generator(seed, size), best);
+        assert!(fails(&best));
+        let res = drop_tokens(&best, 2000, |s| Some(fails(s)));
+        assert!(fails(&res));
+        // the count and a single number that triggers the bug remain
+        assert_eq!(2, res.split_ascii_whitespace().count());
+    }
+
+    #[test]
+    fn test_shrink_stops() {
+        let input = generator(0, 100);
+        let (_, size, best) = shrink_by_size(0, 100, input.clone(), generator, |_, _| None);
+        assert_eq!((100, input.as_str()), (size, best.as_str()));
+        // whatever is found so far is kept once the budget runs out or fails gives up
+        let tried = Cell::new(0);
+        let contains_five = |s: &str| {
+            tried.set(tried.get() + 1);
+            Some(s.contains('5'))
+        };
+        assert_eq!("4 5\n", drop_tokens("1 2 3\n4 5 6\n", 3, contains_five));
+        assert_eq!(3, tried.get());
+        tried.set(0);
+        let res = drop_tokens("1 2 3\n4 5 6\n", 2000, |s| if tried.get() < 2 { contains_five(s) } else { None });
+        assert_eq!("4 5 6\n", res);
+        assert_eq!("5\n", drop_tokens("1 2 3\n4 5 6\n", 2000, |s| Some(s.contains('5'))));
+    }
+}
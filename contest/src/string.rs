@@ -0,0 +1,132 @@
+///
+/// pi[i] is the length of the longest proper border of s[0..=i]
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(vec![0, 0, 1, 2, 0], prefix_function(b"ababc"));
+/// ```
+///
+pub fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut pi = vec![0; n];
+    for i in 1..n {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+///
+/// Start positions of all occurrences of pattern in text, O(|pattern| + |text|)
+///
+pub fn kmp_search<T: Eq>(pattern: &[T], text: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut res = Vec::new();
+    let mut k = 0;
+    for (i, x) in text.iter().enumerate() {
+        while k > 0 && (k == m || *x != pattern[k]) {
+            k = pi[k - 1];
+        }
+        if *x == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            res.push(i + 1 - m);
+        }
+    }
+    res
+}
+
+///
+/// z[i] is the length of the longest common prefix of s and s[i..], z[0] = |s|
+///
+pub fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+///
+/// Palindrome radii by Manacher's algorithm, O(n)
+///
+/// - odd[i] = k means s[i+1-k..i+k] is the longest palindrome centered at i, of length 2k-1
+/// - even[i] = k means s[i-k..i+k] is the longest palindrome centered between i-1 and i,
+///   of length 2k
+///
+pub fn manacher<T: Eq>(s: &[T]) -> (Vec<usize>, Vec<usize>) {
+    let n = s.len();
+    let mut odd = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { odd[l + r - 1 - i].min(r - i) } else { 1 };
+        while i + k < n && i >= k && s[i - k] == s[i + k] {
+            k += 1;
+        }
+        odd[i] = k;
+        if i + k > r {
+            l = i + 1 - k;
+            r = i + k;
+        }
+    }
+    let mut even = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { even[l + r - i].min(r - i) } else { 0 };
+        while i + k < n && i > k && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+        even[i] = k;
+        if i + k > r {
+            l = i - k;
+            r = i + k;
+        }
+    }
+    (odd, even)
+}
+
+///
+/// (start, length) of the leftmost longest palindromic substring
+///
+pub fn longest_palindrome<T: Eq>(s: &[T]) -> (usize, usize) {
+    let (odd, even) = manacher(s);
+    let mut best = (0, 0);
+    for i in 0..s.len() {
+        let (start, len) = (i + 1 - odd[i], 2 * odd[i] - 1);
+        if len > best.1 || (len == best.1 && start < best.0) {
+            best = (start, len);
+        }
+        let (start, len) = (i - even[i], 2 * even[i]);
+        if len > best.1 || (len == best.1 && start < best.0) {
+            best = (start, len);
+        }
+    }
+    best
+}
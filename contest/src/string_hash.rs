@@ -0,0 +1,203 @@
+use std::{cmp::Ordering, sync::OnceLock};
+
+use crate::{
+    binary_search::first_true,
+    math::pow,
+    num_number::{FromNumber, Number},
+    rand::rng,
+    static_modint::{StaticModInt, StaticModulusFactory, MF9223372036737335297},
+};
+
+pub type HashModInt = StaticModInt<i64, MF9223372036737335297>;
+
+///
+/// Two random bases in [2^20, M - 2^20) chosen once per process, every hash built with
+/// `new` uses them so that hashes of different strings are comparable
+///
+/// Random bases make precomputed anti-hash tests useless
+///
+pub fn hash_bases() -> (HashModInt, HashModInt) {
+    static BASES: OnceLock<(i64, i64)> = OnceLock::new();
+    let (a, b) = *BASES.get_or_init(|| {
+        let m = MF9223372036737335297::M;
+        let rng = rng();
+        (rng.range_i64(1 << 20, m - (1 << 20)), rng.range_i64(1 << 20, m - (1 << 20)))
+    });
+    (HashModInt::new(a), HashModInt::new(b))
+}
+
+///
+/// Hash of a string together with its length, so that strings of different length never
+/// collide
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HashValue {
+    pub hash: HashModInt,
+    pub len: usize,
+}
+
+///
+/// Polynomial rolling hash, hash(s) = s[0] * B^(n-1) + s[1] * B^(n-2) + ... + s[n-1]
+/// modulo 9223372036737335297
+///
+/// Substrings are given as half open intervals [l, r)
+///
+/// # Example
+///
+/// ```ignore
+/// let h = StringHash::new(b"abcabc");
+/// assert_eq!(h.hash(0, 3), h.hash(3, 6));
+/// assert_eq!(3, h.lcp(0, 3));
+/// ```
+///
+pub struct StringHash {
+    base: HashModInt,
+    prefix: Vec<HashModInt>,
+    power: Vec<HashModInt>,
+}
+
+impl StringHash {
+    pub fn new<T: Number>(s: &[T]) -> Self {
+        Self::with_base(s, hash_bases().0)
+    }
+
+    pub fn with_base<T: Number>(s: &[T], base: HashModInt) -> Self {
+        let n = s.len();
+        let mut prefix = Vec::with_capacity(n + 1);
+        let mut power = Vec::with_capacity(n + 1);
+        prefix.push(HashModInt::new(0));
+        power.push(HashModInt::new(1));
+        for (i, x) in s.iter().enumerate() {
+            prefix.push(prefix[i] * base + <HashModInt as FromNumber>::from(*x));
+            power.push(power[i] * base);
+        }
+        Self {
+            base,
+            prefix,
+            power,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// B^n
+    ///
+    pub fn power(&self, n: usize) -> HashModInt {
+        if n < self.power.len() {
+            self.power[n]
+        } else {
+            pow(self.base, n)
+        }
+    }
+
+    ///
+    /// Hash of s[l..r]
+    ///
+    pub fn hash(&self, l: usize, r: usize) -> HashValue {
+        HashValue {
+            hash: self.prefix[r] - self.prefix[l] * self.power[r - l],
+            len: r - l,
+        }
+    }
+
+    ///
+    /// Hash of a followed by b
+    ///
+    pub fn concat(&self, a: HashValue, b: HashValue) -> HashValue {
+        HashValue {
+            hash: a.hash * self.power(b.len) + b.hash,
+            len: a.len + b.len,
+        }
+    }
+
+    ///
+    /// Longest common prefix of s[i..] and s[j..], O(log n)
+    ///
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        self.lcp_with(i, self, j)
+    }
+
+    ///
+    /// Longest common prefix of s[i..] and t[j..] where t is hashed with the same base
+    ///
+    pub fn lcp_with(&self, i: usize, t: &StringHash, j: usize) -> usize {
+        let max = (self.len() - i).min(t.len() - j);
+        first_true(0, max, |&k| {
+            k == max || self.hash(i, i + k + 1) != t.hash(j, j + k + 1)
+        })
+        .unwrap()
+    }
+
+    ///
+    /// Compare s[l1..r1] with s[l2..r2] lexicographically, O(log n)
+    ///
+    pub fn compare<T: Ord>(&self, s: &[T], l1: usize, r1: usize, l2: usize, r2: usize) -> Ordering {
+        let max = (r1 - l1).min(r2 - l2);
+        let k = self.lcp(l1, l2).min(max);
+        if k == max {
+            (r1 - l1).cmp(&(r2 - l2))
+        } else {
+            s[l1 + k].cmp(&s[l2 + k])
+        }
+    }
+}
+
+///
+/// 2D rolling hash of a grid, rows use the first base and columns the second one
+///
+/// Rectangles are given as half open intervals [r1, r2) x [c1, c2)
+///
+pub struct GridHash {
+    prefix: Vec<Vec<HashModInt>>,
+    row_power: Vec<HashModInt>,
+    col_power: Vec<HashModInt>,
+}
+
+impl GridHash {
+    pub fn new<T: Number>(grid: &[Vec<T>]) -> Self {
+        let (a, b) = hash_bases();
+        Self::with_bases(grid, a, b)
+    }
+
+    pub fn with_bases<T: Number>(grid: &[Vec<T>], row_base: HashModInt, col_base: HashModInt) -> Self {
+        let n = grid.len();
+        let m = grid.first().map(|x| x.len()).unwrap_or(0);
+        let zero = HashModInt::new(0);
+        let mut prefix = vec![vec![zero; m + 1]; n + 1];
+        for i in 0..n {
+            for j in 0..m {
+                prefix[i + 1][j + 1] = prefix[i][j + 1] * row_base + prefix[i + 1][j] * col_base
+                    - prefix[i][j] * row_base * col_base
+                    + <HashModInt as FromNumber>::from(grid[i][j]);
+            }
+        }
+        let powers = |base: HashModInt, n: usize| {
+            let mut res = vec![HashModInt::new(1); n + 1];
+            for i in 1..=n {
+                res[i] = res[i - 1] * base;
+            }
+            res
+        };
+        Self {
+            prefix,
+            row_power: powers(row_base, n),
+            col_power: powers(col_base, m),
+        }
+    }
+
+    ///
+    /// Hash of the rectangle, equal for equal rectangles of the same shape
+    ///
+    pub fn hash(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> HashModInt {
+        let p = &self.prefix;
+        let (rp, cp) = (self.row_power[r2 - r1], self.col_power[c2 - c1]);
+        p[r2][c2] - p[r1][c2] * rp - p[r2][c1] * cp + p[r1][c1] * rp * cp
+    }
+}
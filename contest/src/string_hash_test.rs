@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::{rand::Rng, string_hash::*};
+
+    fn random_string(rng: &mut Rng, n: usize, alphabet: u64) -> Vec<u32> {
+        (0..n).map(|_| rng.limit_u64(alphabet) as u32).collect()
+    }
+
+    #[test]
+    fn test_string_hash() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..100 {
+            let n = rng.range_usize(0, 16);
+            let s = random_string(&mut rng, n, 2);
+            let h = StringHash::new(&s);
+            for l1 in 0..=n {
+                for r1 in l1..=n {
+                    for l2 in 0..=n {
+                        for r2 in l2..=n {
+                            let (a, b) = (&s[l1..r1], &s[l2..r2]);
+                            assert_eq!(a == b, h.hash(l1, r1) == h.hash(l2, r2));
+                            assert_eq!(a.cmp(b), h.compare(&s, l1, r1, l2, r2));
+                            if r1 == l2 {
+                                assert_eq!(h.hash(l1, r2), h.concat(h.hash(l1, r1), h.hash(l2, r2)));
+                            }
+                        }
+                    }
+                }
+            }
+            for i in 0..=n {
+                for j in 0..=n {
+                    let expect = s[i..].iter().zip(&s[j..]).take_while(|(x, y)| x == y).count();
+                    assert_eq!(expect, h.lcp(i, j));
+                }
+            }
+        }
+        let (s, t) = (b"abcab", b"xxab");
+        let (hs, ht) = (StringHash::new(s), StringHash::new(t));
+        assert_eq!(hs.hash(3, 5), ht.hash(2, 4));
+        assert_eq!(2, hs.lcp_with(0, &ht, 2));
+    }
+
+    #[test]
+    fn test_grid_hash() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..20 {
+            let (n, m) = (rng.range_usize(1, 5), rng.range_usize(1, 5));
+            let grid: Vec<Vec<u32>> = (0..n).map(|_| random_string(&mut rng, m, 2)).collect();
+            let h = GridHash::new(&grid);
+            let rect = |r1: usize, c1: usize, r2: usize, c2: usize| -> Vec<Vec<u32>> {
+                grid[r1..r2].iter().map(|row| row[c1..c2].to_vec()).collect()
+            };
+            for r1 in 0..n {
+                for c1 in 0..m {
+                    for r2 in r1 + 1..=n {
+                        for c2 in c1 + 1..=m {
+                            for x in 0..=n - (r2 - r1) {
+                                for y in 0..=m - (c2 - c1) {
+                                    let (x2, y2) = (x + r2 - r1, y + c2 - c1);
+                                    assert_eq!(
+                                        rect(r1, c1, r2, c2) == rect(x, y, x2, y2),
+                                        h.hash(r1, c1, r2, c2) == h.hash(x, y, x2, y2)
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use crate::{rand::Rng, string::*};
+
+    fn random_string(rng: &mut Rng, n: usize, alphabet: u64) -> Vec<u32> {
+        (0..n).map(|_| rng.limit_u64(alphabet) as u32).collect()
+    }
+
+    fn is_palindrome<T: Eq>(s: &[T]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn test_prefix_function() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 30);
+            let s = random_string(&mut rng, n, 3);
+            let pi = prefix_function(&s);
+            for i in 0..n {
+                let expect = (0..=i).filter(|&k| s[..k] == s[i + 1 - k..=i]).max().unwrap();
+                assert_eq!(expect, pi[i]);
+            }
+        }
+        assert_eq!(vec![0, 0, 1, 2, 0], prefix_function(b"ababc"));
+    }
+
+    #[test]
+    fn test_kmp_search() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let (n, m) = (rng.range_usize(0, 40), rng.range_usize(1, 4));
+            let text = random_string(&mut rng, n, 2);
+            let pattern = random_string(&mut rng, m, 2);
+            let expect: Vec<usize> = (0..text.len())
+                .filter(|&i| text[i..].starts_with(&pattern))
+                .collect();
+            assert_eq!(expect, kmp_search(&pattern, &text));
+        }
+        let text: Vec<char> = "aaaa".chars().collect();
+        assert_eq!(vec![0, 1, 2], kmp_search(&['a', 'a'], &text));
+    }
+
+    #[test]
+    fn test_z_function() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 30);
+            let s = random_string(&mut rng, n, 3);
+            let z = z_function(&s);
+            for i in 0..n {
+                let expect = (0..n - i).take_while(|&k| s[k] == s[i + k]).count();
+                assert_eq!(expect, z[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_manacher() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..300 {
+            let n = rng.range_usize(0, 30);
+            let s = random_string(&mut rng, n, 2);
+            let (odd, even) = manacher(&s);
+            for i in 0..n {
+                let expect = (1..=i + 1)
+                    .take_while(|&k| i + k <= n && is_palindrome(&s[i + 1 - k..i + k]))
+                    .count();
+                assert_eq!(expect, odd[i]);
+                let expect = (1..=i)
+                    .take_while(|&k| i + k <= n && is_palindrome(&s[i - k..i + k]))
+                    .count();
+                assert_eq!(expect, even[i]);
+            }
+            let (start, len) = longest_palindrome(&s);
+            let best = (0..=n).rev().find(|&len| (0..=n - len).any(|l| is_palindrome(&s[l..l + len]))).unwrap();
+            assert_eq!(best, len);
+            assert!(is_palindrome(&s[start..start + len]));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::range_minimum_query::RangeMinimumQuery;
+
+///
+/// Suffix array of s by SA-IS, every s[i] must be in [0, upper), O(n + upper)
+///
+/// sa[i] is the start of the i-th smallest suffix
+///
+pub fn suffix_array_int(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+    let mut sa = vec![0; n];
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if s[i] == s[i + 1] { ls[i + 1] } else { s[i] < s[i + 1] };
+    }
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if !ls[i] {
+            sum_s[s[i]] += 1;
+        } else {
+            sum_l[s[i] + 1] += 1;
+        }
+    }
+    for i in 0..=upper {
+        sum_s[i] += sum_l[i];
+        if i < upper {
+            sum_l[i + 1] += sum_s[i];
+        }
+    }
+
+    const NONE: usize = usize::MAX;
+    let induce = |sa: &mut [usize], lms: &[usize]| {
+        sa.fill(NONE);
+        let mut buf = sum_s.clone();
+        for &d in lms {
+            if d == n {
+                continue;
+            }
+            sa[buf[s[d]]] = d;
+            buf[s[d]] += 1;
+        }
+        buf.copy_from_slice(&sum_l);
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != NONE && v >= 1 && !ls[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+        buf.copy_from_slice(&sum_l);
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != NONE && v >= 1 && ls[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    };
+
+    let mut lms_map = vec![NONE; n + 1];
+    let mut m = 0;
+    for i in 1..n {
+        if !ls[i - 1] && ls[i] {
+            lms_map[i] = m;
+            m += 1;
+        }
+    }
+    let lms: Vec<usize> = (1..n).filter(|&i| !ls[i - 1] && ls[i]).collect();
+    induce(&mut sa, &lms);
+
+    if m > 0 {
+        let sorted_lms: Vec<usize> = sa.iter().copied().filter(|&v| lms_map[v] != NONE).collect();
+        let mut rec_s = vec![0; m];
+        let mut rec_upper = 0;
+        rec_s[lms_map[sorted_lms[0]]] = 0;
+        for i in 1..m {
+            let (mut l, mut r) = (sorted_lms[i - 1], sorted_lms[i]);
+            let end_l = if lms_map[l] + 1 < m { lms[lms_map[l] + 1] } else { n };
+            let end_r = if lms_map[r] + 1 < m { lms[lms_map[r] + 1] } else { n };
+            let mut same = true;
+            if end_l - l != end_r - r {
+                same = false;
+            } else {
+                while l < end_l {
+                    if s[l] != s[r] {
+                        break;
+                    }
+                    l += 1;
+                    r += 1;
+                }
+                if l == n || s[l] != s[r] {
+                    same = false;
+                }
+            }
+            if !same {
+                rec_upper += 1;
+            }
+            rec_s[lms_map[sorted_lms[i]]] = rec_upper;
+        }
+        let rec_sa = suffix_array_int(&rec_s, rec_upper + 1);
+        let sorted_lms: Vec<usize> = rec_sa.iter().map(|&i| lms[i]).collect();
+        induce(&mut sa, &sorted_lms);
+    }
+    sa
+}
+
+///
+/// Suffix array of a byte string
+///
+pub fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let s: Vec<usize> = s.iter().map(|&x| x as usize).collect();
+    suffix_array_int(&s, 256)
+}
+
+///
+/// LCP array by Kasai's algorithm, lcp[i] is the longest common prefix of the suffixes
+/// sa[i] and sa[i+1], O(n)
+///
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n - 1];
+    let mut h: usize = 0;
+    for i in 0..n {
+        h = h.saturating_sub(1);
+        if rank[i] == 0 {
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+    }
+    lcp
+}
+
+///
+/// Suffix array with ranks and LCP, answering LCP of any two suffixes in O(1)
+///
+/// # Example
+///
+/// ```ignore
+/// let sa = SuffixArray::from_bytes(b"banana");
+/// assert_eq!(vec![5, 3, 1, 0, 4, 2], sa.sa);
+/// assert_eq!(3, sa.lcp(1, 3));
+/// assert_eq!(15, sa.distinct_substrings());
+/// ```
+///
+pub struct SuffixArray {
+    pub sa: Vec<usize>,
+    pub rank: Vec<usize>,
+    pub lcp: Vec<usize>,
+    rmq: Option<RangeMinimumQuery<usize>>,
+}
+
+impl SuffixArray {
+    ///
+    /// every s[i] must be in [0, upper)
+    ///
+    pub fn new(s: &[usize], upper: usize) -> Self {
+        let sa = suffix_array_int(s, upper);
+        Self::with_sa(s, sa)
+    }
+
+    pub fn from_bytes(s: &[u8]) -> Self {
+        let sa = suffix_array(s);
+        Self::with_sa(s, sa)
+    }
+
+    fn with_sa<T: Eq>(s: &[T], sa: Vec<usize>) -> Self {
+        let mut rank = vec![0; sa.len()];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+        let lcp = lcp_array(s, &sa);
+        let rmq = if lcp.is_empty() {
+            None
+        } else {
+            Some(RangeMinimumQuery::new(lcp.clone()))
+        };
+        Self { sa, rank, lcp, rmq }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sa.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sa.is_empty()
+    }
+
+    ///
+    /// Longest common prefix of the suffixes starting at i and j
+    ///
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.len() - i;
+        }
+        let (a, b) = (self.rank[i].min(self.rank[j]), self.rank[i].max(self.rank[j]));
+        self.rmq.as_ref().unwrap().query(a, b - 1)
+    }
+
+    ///
+    /// Number of distinct non-empty substrings
+    ///
+    pub fn distinct_substrings(&self) -> u64 {
+        let n = self.len() as u64;
+        n * (n + 1) / 2 - self.lcp.iter().map(|&x| x as u64).sum::<u64>()
+    }
+
+    ///
+    /// (start, length) of a longest substring occurring at least twice, the occurrences may
+    /// overlap, length 0 if there is none
+    ///
+    pub fn longest_repeated_substring(&self) -> (usize, usize) {
+        let mut best = (0, 0);
+        for (i, &h) in self.lcp.iter().enumerate() {
+            if h > best.1 {
+                best = (self.sa[i], h);
+            }
+        }
+        best
+    }
+
+    ///
+    /// (start, length) of the k-th (0-indexed) smallest distinct non-empty substring
+    ///
+    pub fn kth_substring(&self, mut k: u64) -> Option<(usize, usize)> {
+        let n = self.len();
+        for i in 0..n {
+            let skip = if i == 0 { 0 } else { self.lcp[i - 1] };
+            let fresh = (n - self.sa[i] - skip) as u64;
+            if k < fresh {
+                return Some((self.sa[i], skip + k as usize + 1));
+            }
+            k -= fresh;
+        }
+        None
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::{rand::Rng, string::kmp_search, suffix_array::*};
+
+    fn naive_sa(s: &[usize]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..s.len()).collect();
+        sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+        sa
+    }
+
+    #[test]
+    fn test_suffix_array() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..500 {
+            let n = rng.range_usize(0, 60);
+            let upper = rng.range_usize(1, 5);
+            let s: Vec<usize> = (0..n).map(|_| rng.limit_u64(upper as u64) as usize).collect();
+            let sa = naive_sa(&s);
+            assert_eq!(sa, suffix_array_int(&s, upper));
+            let lcp = lcp_array(&s, &sa);
+            for i in 0..n.saturating_sub(1) {
+                let (a, b) = (&s[sa[i]..], &s[sa[i + 1]..]);
+                assert_eq!(a.iter().zip(b).take_while(|(x, y)| x == y).count(), lcp[i]);
+            }
+        }
+        assert_eq!(vec![5, 3, 1, 0, 4, 2], suffix_array(b"banana"));
+    }
+
+    #[test]
+    fn test_suffix_array_queries() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let n = rng.range_usize(0, 30);
+            let s: Vec<usize> = (0..n).map(|_| rng.limit_u64(3) as usize).collect();
+            let sa = SuffixArray::new(&s, 3);
+            for i in 0..n {
+                for j in 0..n {
+                    let expect = s[i..].iter().zip(&s[j..]).take_while(|(x, y)| x == y).count();
+                    assert_eq!(expect, sa.lcp(i, j));
+                }
+            }
+            let mut subs: Vec<&[usize]> = (0..n)
+                .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+                .map(|(i, j)| &s[i..j])
+                .collect();
+            subs.sort();
+            subs.dedup();
+            assert_eq!(subs.len() as u64, sa.distinct_substrings());
+            for (k, sub) in subs.iter().enumerate() {
+                let (start, len) = sa.kth_substring(k as u64).unwrap();
+                assert_eq!(*sub, &s[start..start + len]);
+            }
+            assert_eq!(None, sa.kth_substring(subs.len() as u64));
+            let longest = (1..=n)
+                .filter(|&len| (0..=n - len).any(|i| (i + 1..=n - len).any(|j| s[i..i + len] == s[j..j + len])))
+                .max()
+                .unwrap_or(0);
+            let (start, len) = sa.longest_repeated_substring();
+            assert_eq!(longest, len);
+            assert!(len == 0 || kmp_search(&s[start..start + len], &s).len() >= 2);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::BTreeMap;
+
+pub const NIL: usize = 0;
+pub const ROOT: usize = 1;
+
+///
+/// Outgoing edges of a state, NIL for no edge
+///
+pub trait Transition: Clone {
+    fn empty() -> Self;
+    fn get(&self, c: usize) -> usize;
+    fn set(&mut self, c: usize, to: usize);
+    ///
+    /// Visit (c, to) for every existing edge in increasing order of c
+    ///
+    fn for_each(&self, f: impl FnMut(usize, usize));
+}
+
+impl<const C: usize> Transition for [usize; C] {
+    fn empty() -> Self {
+        [NIL; C]
+    }
+    fn get(&self, c: usize) -> usize {
+        self[c]
+    }
+    fn set(&mut self, c: usize, to: usize) {
+        self[c] = to;
+    }
+    fn for_each(&self, mut f: impl FnMut(usize, usize)) {
+        for (c, &to) in self.iter().enumerate() {
+            if to != NIL {
+                f(c, to);
+            }
+        }
+    }
+}
+
+impl Transition for BTreeMap<usize, usize> {
+    fn empty() -> Self {
+        BTreeMap::new()
+    }
+    fn get(&self, c: usize) -> usize {
+        self.get(&c).copied().unwrap_or(NIL)
+    }
+    fn set(&mut self, c: usize, to: usize) {
+        self.insert(c, to);
+    }
+    fn for_each(&self, mut f: impl FnMut(usize, usize)) {
+        for (&c, &to) in self.iter() {
+            f(c, to);
+        }
+    }
+}
+
+pub struct State<T: Transition> {
+    ///
+    /// length of the longest substring of the state
+    ///
+    pub len: usize,
+    pub link: usize,
+    pub next: T,
+    ///
+    /// number of prefixes of the inserted strings ending exactly at this state
+    ///
+    terminal: u64,
+}
+
+///
+/// Suffix automaton over characters in [0, C), state NIL is a sentinel and ROOT is the
+/// empty string
+///
+/// Several strings can be inserted with `add_string`, which builds the general suffix
+/// automaton of all of them
+///
+/// # Example
+///
+/// ```ignore
+/// let mut sam = ArraySuffixAutomaton::<26>::new(0);
+/// sam.add_string(b"abab".iter().map(|x| (x - b'a') as usize));
+/// assert_eq!(7, sam.distinct_substrings());
+/// let occ = sam.occurrences();
+/// assert_eq!(2, occ[sam.find(b"ab".iter().map(|x| (x - b'a') as usize))]);
+/// ```
+///
+pub struct SuffixAutomaton<T: Transition> {
+    nodes: Vec<State<T>>,
+    last: usize,
+}
+
+///
+/// Array transitions, fast for small alphabets
+///
+pub type ArraySuffixAutomaton<const C: usize> = SuffixAutomaton<[usize; C]>;
+///
+/// Ordered map transitions, for large or sparse alphabets
+///
+pub type MapSuffixAutomaton = SuffixAutomaton<BTreeMap<usize, usize>>;
+
+impl<T: Transition> SuffixAutomaton<T> {
+    pub fn new(estimate_len: usize) -> Self {
+        let mut ans = Self {
+            nodes: Vec::with_capacity(2 + 2 * estimate_len),
+            last: ROOT,
+        };
+        ans.new_node(0, NIL);
+        ans.new_node(0, NIL);
+        ans
+    }
+
+    fn new_node(&mut self, len: usize, link: usize) -> usize {
+        self.nodes.push(State {
+            len,
+            link,
+            next: T::empty(),
+            terminal: 0,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn clone_node(&mut self, q: usize, len: usize) -> usize {
+        let clone = self.new_node(len, self.nodes[q].link);
+        self.nodes[clone].next = self.nodes[q].next.clone();
+        self.nodes[q].link = clone;
+        clone
+    }
+
+    ///
+    /// Number of states including NIL
+    ///
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn node(&self, state: usize) -> &State<T> {
+        &self.nodes[state]
+    }
+
+    pub fn next(&self, state: usize, c: usize) -> usize {
+        self.nodes[state].next.get(c)
+    }
+
+    ///
+    /// State of the whole string inserted so far
+    ///
+    pub fn last(&self) -> usize {
+        self.last
+    }
+
+    ///
+    /// Start a new string, the following `extend` calls append to it
+    ///
+    pub fn reset(&mut self) {
+        self.last = ROOT;
+    }
+
+    ///
+    /// Append c to the current string, return the state of the whole current string
+    ///
+    pub fn extend(&mut self, c: usize) -> usize {
+        let last = self.last;
+        let q = self.next(last, c);
+        if q != NIL {
+            let state = if self.nodes[last].len + 1 == self.nodes[q].len {
+                q
+            } else {
+                let clone = self.clone_node(q, self.nodes[last].len + 1);
+                let mut p = last;
+                while p != NIL && self.next(p, c) == q {
+                    self.nodes[p].next.set(c, clone);
+                    p = self.nodes[p].link;
+                }
+                clone
+            };
+            self.nodes[state].terminal += 1;
+            self.last = state;
+            return state;
+        }
+        let cur = self.new_node(self.nodes[last].len + 1, ROOT);
+        self.nodes[cur].terminal = 1;
+        let mut p = last;
+        while p != NIL && self.next(p, c) == NIL {
+            self.nodes[p].next.set(c, cur);
+            p = self.nodes[p].link;
+        }
+        if p != NIL {
+            let q = self.next(p, c);
+            if self.nodes[p].len + 1 == self.nodes[q].len {
+                self.nodes[cur].link = q;
+            } else {
+                let clone = self.clone_node(q, self.nodes[p].len + 1);
+                while p != NIL && self.next(p, c) == q {
+                    self.nodes[p].next.set(c, clone);
+                    p = self.nodes[p].link;
+                }
+                self.nodes[cur].link = clone;
+            }
+        }
+        self.last = cur;
+        cur
+    }
+
+    ///
+    /// Insert one more string
+    ///
+    pub fn add_string(&mut self, s: impl IntoIterator<Item = usize>) {
+        self.reset();
+        for c in s {
+            self.extend(c);
+        }
+    }
+
+    ///
+    /// State reached by reading s from ROOT, NIL if s is not a substring
+    ///
+    pub fn find(&self, s: impl IntoIterator<Item = usize>) -> usize {
+        let mut state = ROOT;
+        for c in s {
+            state = self.next(state, c);
+            if state == NIL {
+                break;
+            }
+        }
+        state
+    }
+
+    ///
+    /// Length of the shortest substring of the state
+    ///
+    pub fn shortest(&self, state: usize) -> usize {
+        self.nodes[self.nodes[state].link].len + 1
+    }
+
+    ///
+    /// Length of the longest substring of the state
+    ///
+    pub fn longest(&self, state: usize) -> usize {
+        self.nodes[state].len
+    }
+
+    ///
+    /// States except NIL ordered by len, so a state always goes after its suffix link
+    ///
+    pub fn topological_order(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let max_len = self.nodes.iter().map(|x| x.len).max().unwrap();
+        let mut cnt = vec![0; max_len + 2];
+        for i in ROOT..n {
+            cnt[self.nodes[i].len + 1] += 1;
+        }
+        for i in 1..cnt.len() {
+            cnt[i] += cnt[i - 1];
+        }
+        let mut order = vec![0; n - ROOT];
+        for i in ROOT..n {
+            let len = self.nodes[i].len;
+            order[cnt[len]] = i;
+            cnt[len] += 1;
+        }
+        order
+    }
+
+    ///
+    /// Children of every state in the suffix link tree rooted at ROOT
+    ///
+    pub fn link_tree(&self) -> Vec<Vec<usize>> {
+        let mut res = vec![vec![]; self.nodes.len()];
+        for i in ROOT + 1..self.nodes.len() {
+            res[self.nodes[i].link].push(i);
+        }
+        res
+    }
+
+    ///
+    /// |endpos| of every state, that is how many times each of its substrings occurs,
+    /// summed over all inserted strings
+    ///
+    pub fn occurrences(&self) -> Vec<u64> {
+        let mut res: Vec<u64> = self.nodes.iter().map(|x| x.terminal).collect();
+        for &i in self.topological_order().iter().rev() {
+            let link = self.nodes[i].link;
+            if link != NIL {
+                res[link] += res[i];
+            }
+        }
+        res[ROOT] = 0;
+        res
+    }
+
+    ///
+    /// Number of distinct non-empty substrings of the inserted strings
+    ///
+    pub fn distinct_substrings(&self) -> u64 {
+        (ROOT + 1..self.nodes.len())
+            .map(|i| (self.longest(i) - self.shortest(i) + 1) as u64)
+            .sum()
+    }
+}
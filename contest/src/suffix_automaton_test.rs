@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{rand::Rng, suffix_automaton::*};
+
+    fn random_string(rng: &mut Rng, n: usize, alphabet: u64) -> Vec<usize> {
+        (0..n).map(|_| rng.limit_u64(alphabet) as usize).collect()
+    }
+
+    fn substring_counts(strings: &[Vec<usize>]) -> BTreeMap<Vec<usize>, u64> {
+        let mut res = BTreeMap::new();
+        for s in strings {
+            for i in 0..s.len() {
+                for j in i + 1..=s.len() {
+                    *res.entry(s[i..j].to_vec()).or_insert(0) += 1;
+                }
+            }
+        }
+        res
+    }
+
+    fn check<T: Transition>(sam: &SuffixAutomaton<T>, strings: &[Vec<usize>]) {
+        let expect = substring_counts(strings);
+        assert_eq!(expect.len() as u64, sam.distinct_substrings());
+        let occ = sam.occurrences();
+        let mut lens = vec![vec![]; sam.size()];
+        for (sub, &cnt) in &expect {
+            let state = sam.find(sub.iter().copied());
+            assert_ne!(NIL, state);
+            assert_eq!(cnt, occ[state]);
+            lens[state].push(sub.len());
+        }
+        for (state, lens) in lens.iter().enumerate().skip(ROOT + 1) {
+            assert_eq!(Some(&sam.shortest(state)), lens.iter().min());
+            assert_eq!(Some(&sam.longest(state)), lens.iter().max());
+        }
+        assert_eq!(NIL, sam.find(vec![9, 9]));
+    }
+
+    #[test]
+    fn test_suffix_automaton() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let n = rng.range_usize(0, 30);
+            let s = random_string(&mut rng, n, 3);
+            let mut sam = ArraySuffixAutomaton::<10>::new(n);
+            sam.add_string(s.iter().copied());
+            assert!(sam.size() <= 2 * n.max(1) + 1);
+            check(&sam, &[s]);
+        }
+    }
+
+    #[test]
+    fn test_general_suffix_automaton() {
+        let mut rng = Rng::new_with_seed(0);
+        for _ in 0..200 {
+            let k = rng.range_usize(1, 4);
+            let strings: Vec<Vec<usize>> = (0..k)
+                .map(|_| {
+                    let n = rng.range_usize(0, 12);
+                    random_string(&mut rng, n, 2)
+                })
+                .collect();
+            let mut sam = MapSuffixAutomaton::new(0);
+            for s in &strings {
+                sam.add_string(s.iter().copied());
+            }
+            check(&sam, &strings);
+            let tree = sam.link_tree();
+            let order = sam.topological_order();
+            assert_eq!(ROOT, order[0]);
+            for (p, children) in tree.iter().enumerate() {
+                for &c in children {
+                    assert_eq!(p, sam.node(c).link);
+                    assert!(sam.node(p).len < sam.node(c).len);
+                }
+            }
+        }
+    }
+}
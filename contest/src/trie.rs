@@ -1,6 +1,6 @@
 use crate::{algebraic_structure::Monoid, macros::should_eq};
-const NIL: usize = 0;
-const ROOT: usize = 1;
+pub const NIL: usize = 0;
+pub const ROOT: usize = 1;
 
 pub trait Charset {
     const CHARSET: usize;
@@ -43,6 +43,20 @@ impl<S: Monoid, const C: usize> Trie<S, C> {
         self.update_rec(ROOT, road, u)
     }
 
+    ///
+    /// Create the path of road if absent without touching the sums, return its end node
+    ///
+    pub fn insert(&mut self, road: &mut impl Iterator<Item = usize>) -> usize {
+        let mut root = ROOT;
+        for index in road {
+            if self.nodes[root].adj[index] == NIL {
+                self.nodes[root].adj[index] = self.new_node();
+            }
+            root = self.nodes[root].adj[index];
+        }
+        root
+    }
+
     pub fn query(&self, road: &mut impl Iterator<Item = usize>) -> usize {
         self.query_internal(ROOT, road)
     }
@@ -136,4 +150,15 @@ impl<S: Monoid, const C: usize> Trie<S, C> {
     pub fn node(&mut self, root: usize) -> &mut Row<S, C> {
         &mut self.nodes[root]
     }
+
+    pub fn row(&self, root: usize) -> &Row<S, C> {
+        &self.nodes[root]
+    }
+
+    ///
+    /// Number of nodes including NIL
+    ///
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
 }
@@ -0,0 +1,2 @@
+SET RUST_BACKTRACE=1
+cargo run -p contest --features samples